@@ -5,9 +5,11 @@
 
 #![warn(missing_docs)]
 
+pub mod budget_proposal;
+
 use std::sync::Arc;
 
-use parachain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use parachain_template_runtime::{configs::AssetKind, opaque::Block, AccountId, Balance, Nonce};
 
 use polkadot_sdk::*;
 
@@ -40,9 +42,11 @@ where
 		+ 'static,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: ::budget_proposal::runtime_api::BudgetProposalApi<Block, AccountId, AssetKind, Balance>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use budget_proposal::{BudgetProposal, BudgetProposalApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -50,6 +54,7 @@ where
 	let FullDeps { client, pool } = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(BudgetProposal::new(client).into_rpc())?;
 	Ok(module)
 }
@@ -0,0 +1,163 @@
+//! RPC methods for listing budget proposals and their totals.
+
+use std::sync::Arc;
+
+use budget_proposal::{
+	runtime_api::BudgetProposalApi as BudgetProposalRuntimeApi, ProposalFilter, ProposalStatus,
+};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+};
+use parachain_template_runtime::{configs::AssetKind, opaque::Block, AccountId, Balance};
+use serde::{Deserialize, Serialize};
+
+use polkadot_sdk::*;
+
+use frame_support::traits::fungible::NativeOrWithId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// A budget proposal as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcProposal {
+	/// The ID of the proposal.
+	pub id: u64,
+	/// The creator of the proposal.
+	pub creator: AccountId,
+	/// The account that receives the funds.
+	pub beneficiary: AccountId,
+	/// The amount requested.
+	pub amount: NumberOrHex,
+	/// The asset requested, or `None` for the native token.
+	pub asset: Option<u32>,
+	/// The budget category of the proposal.
+	pub category: u32,
+	/// The status of the proposal.
+	pub status: ProposalStatus,
+	/// The amount paid out so far.
+	pub disbursed: NumberOrHex,
+	/// The block at which the proposal was created.
+	pub created_at: u64,
+	/// The block at which the proposal was last updated.
+	pub updated_at: u64,
+}
+
+/// Amounts requested by pending, approved and rejected proposals in one asset.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcProposalTotals {
+	/// Proposals still awaiting a decision.
+	pub pending: NumberOrHex,
+	/// Proposals approved, whether or not they have been paid out or clawed back.
+	pub approved: NumberOrHex,
+	/// Proposals rejected or vetoed.
+	pub rejected: NumberOrHex,
+}
+
+/// Budget proposal RPC methods.
+#[rpc(client, server)]
+pub trait BudgetProposalApi<BlockHash> {
+	/// List up to `limit` proposals matching `filter`, in ID order starting from `start`.
+	///
+	/// The next page starts after the ID of the last proposal returned.
+	#[method(name = "budgetProposal_proposals")]
+	fn proposals(
+		&self,
+		filter: ProposalFilter<AccountId, Balance>,
+		start: u64,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcProposal>>;
+
+	/// Amounts requested in `asset` by pending, approved and rejected proposals. `None`
+	/// selects the native token.
+	#[method(name = "budgetProposal_totals")]
+	fn totals(&self, asset: Option<u32>, at: Option<BlockHash>) -> RpcResult<RpcProposalTotals>;
+}
+
+/// Provides budget proposal RPC methods backed by the runtime API.
+pub struct BudgetProposal<C> {
+	client: Arc<C>,
+}
+
+impl<C> BudgetProposal<C> {
+	/// Create new `BudgetProposal` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> BudgetProposalApiServer<<Block as BlockT>::Hash> for BudgetProposal<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BudgetProposalRuntimeApi<Block, AccountId, AssetKind, Balance>,
+{
+	fn proposals(
+		&self,
+		filter: ProposalFilter<AccountId, Balance>,
+		start: u64,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcProposal>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let proposals = self
+			.client
+			.runtime_api()
+			.proposals(at, filter, start, limit)
+			.map_err(runtime_error)?;
+
+		Ok(proposals
+			.into_iter()
+			.map(|(id, proposal)| RpcProposal {
+				id,
+				creator: proposal.creator,
+				beneficiary: proposal.beneficiary,
+				amount: proposal.amount.into(),
+				asset: asset_id(proposal.asset),
+				category: proposal.category,
+				status: proposal.status,
+				disbursed: proposal.disbursed.into(),
+				created_at: proposal.created_at,
+				updated_at: proposal.updated_at,
+			})
+			.collect())
+	}
+
+	fn totals(
+		&self,
+		asset: Option<u32>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcProposalTotals> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let asset = asset.map_or(NativeOrWithId::Native, NativeOrWithId::WithId);
+		let totals =
+			self.client.runtime_api().proposal_totals(at, asset).map_err(runtime_error)?;
+
+		Ok(RpcProposalTotals {
+			pending: totals.pending.into(),
+			approved: totals.approved.into(),
+			rejected: totals.rejected.into(),
+		})
+	}
+}
+
+/// The ID of a non-native asset.
+fn asset_id(asset: AssetKind) -> Option<u32> {
+	match asset {
+		NativeOrWithId::Native => None,
+		NativeOrWithId::WithId(id) => Some(id),
+	}
+}
+
+/// Convert a runtime API error into an RPC error.
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query budget proposals.", Some(format!("{:?}", err)))
+}
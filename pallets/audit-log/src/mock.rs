@@ -36,7 +36,7 @@ impl frame_system::Config for Test {
 // AuditLog pallet configuration
 impl audit_log_pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = pallet_name::weights::SubstrateWeight<Test>;
+    type WeightInfo = crate::weights::SubstrateWeight<Test>;
 }
 
 // Test externalities initialization
@@ -2,28 +2,179 @@
 
 use super::{Pallet as BudgetProposalPallet, *};
 use frame::deps::frame_support::assert_ok;
-use frame::{deps::frame_benchmarking::v2::*, prelude::*};
+use frame::{
+    deps::frame_benchmarking::v2::*,
+    prelude::*,
+    traits::{
+        fungibles::{Inspect, Mutate},
+        Bounded,
+    },
+};
+use frame_system::RawOrigin;
+
+// Register a budget category for benchmarked proposals to draw on.
+fn setup_category<T: Config>() -> u32 {
+    let category = NextCategoryId::<T>::get();
+    NextCategoryId::<T>::put(category + 1);
+    BudgetCategories::<T>::insert(
+        category,
+        BudgetCategory { name: BoundedVec::truncate_from(b"General".to_vec()) },
+    );
+    category
+}
+
+// Create a recurring line paying `amount` every 10 blocks, first in the next block.
+fn setup_recurring_line<T: Config>(
+    origin: T::RuntimeOrigin,
+    category: u32,
+    amount: T::Balance,
+) -> Result<u32, BenchmarkError> {
+    let line_id = NextRecurringLineId::<T>::get();
+    let now: u64 = frame_system::Pallet::<T>::block_number()
+        .try_into()
+        .ok()
+        .expect("block number fits in u64");
+    BudgetProposalPallet::<T>::create_recurring_line(
+        origin,
+        account("beneficiary", line_id, 0),
+        T::BenchmarkHelper::asset(),
+        amount,
+        category,
+        10,
+        now + 1,
+        now + 1_000,
+    )
+    .map_err(|_| BenchmarkError::Weightless)?;
+    Ok(line_id)
+}
+
+// Endorse `proposal_id` by as many accounts as it needs to be reviewed.
+fn endorse<T: Config>(proposal_id: u64) {
+    let amount = BudgetProposals::<T>::get(proposal_id).expect("proposal exists").amount;
+    for i in 0..BudgetProposalPallet::<T>::required_endorsements(amount) {
+        assert_ok!(BudgetProposalPallet::<T>::endorse_proposal(
+            RawOrigin::Signed(account("endorser", i, 0)).into(),
+            proposal_id
+        ));
+    }
+}
+
+// Approve `proposal_id` through `ApproverOrigin`.
+fn approve<T: Config>(proposal_id: u64) {
+    let origin = T::ApproverOrigin::try_successful_origin().expect("approver origin exists");
+    assert_ok!(BudgetProposalPallet::<T>::approve_proposal(origin, proposal_id));
+}
+
+// Create and approve `n` lump-sum proposals paying `beneficiary`, then fund the treasury and
+// move past their challenge period so they can be executed together. Returns their IDs.
+fn setup_approved_proposals<T: Config>(n: u32, beneficiary: &T::AccountId) -> Vec<u64> {
+    let creator: T::AccountId = whitelisted_caller();
+    let category = setup_category::<T>();
+    let asset = T::BenchmarkHelper::asset();
+    let amount = T::Assets::minimum_balance(asset.clone()).max(1u32.into()) * 10u32.into();
+    assert_ok!(T::Assets::mint_into(
+        asset.clone(),
+        &BudgetProposalPallet::<T>::treasury_account(),
+        amount * (n + 1).into()
+    ));
+
+    let mut proposal_ids = Vec::new();
+    for _ in 0..n {
+        let proposal_id = NextProposalId::<T>::get();
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            amount,
+            b"Bridge construction".to_vec(),
+            category,
+            asset.clone(),
+            beneficiary.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(proposal_id);
+        approve::<T>(proposal_id);
+        proposal_ids.push(proposal_id);
+    }
+
+    let now: u64 = frame_system::Pallet::<T>::block_number()
+        .try_into()
+        .ok()
+        .expect("block number fits in u64");
+    frame_system::Pallet::<T>::set_block_number(
+        (now + T::ChallengePeriod::get()).try_into().ok().expect("block number fits"),
+    );
+    proposal_ids
+}
+
+// Open an approval round and enter `n` pending proposals into it. Returns the round ID and
+// the proposal IDs.
+fn setup_round<T: Config>(
+    origin: T::RuntimeOrigin,
+    n: u32,
+) -> Result<(u32, Vec<u64>), BenchmarkError> {
+    let creator: T::AccountId = whitelisted_caller();
+    let category = setup_category::<T>();
+    let asset = T::BenchmarkHelper::asset();
+    let amount: T::Balance = 1000u32.into();
+    let round_id = NextRoundId::<T>::get();
+    BudgetProposalPallet::<T>::open_round(
+        origin,
+        amount * (n + 1).into(),
+        asset.clone(),
+        RoundVoting::Approval,
+        100,
+    )
+    .map_err(|_| BenchmarkError::Weightless)?;
+
+    let mut proposal_ids = Vec::new();
+    for _ in 0..n {
+        let proposal_id = NextProposalId::<T>::get();
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            amount,
+            b"Neighbourhood playground".to_vec(),
+            category,
+            asset.clone(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(proposal_id);
+        assert_ok!(BudgetProposalPallet::<T>::enter_round(
+            RawOrigin::Signed(creator.clone()).into(),
+            round_id,
+            proposal_id
+        ));
+        proposal_ids.push(proposal_id);
+    }
+    Ok((round_id, proposal_ids))
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
     #[cfg(test)]
     use crate::pallet::Pallet as BudgetProposalPallet;
-    use frame_system::RawOrigin;
 
     #[benchmark]
     fn create_proposal() {
         let caller: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
         
         // Setup: Prepare valid proposal data
-        let amount = 1000u128;
+        let amount: T::Balance = 1000u32.into();
         let purpose = b"Community development project".to_vec();
         
         #[extrinsic_call]
         create_proposal(
             RawOrigin::Signed(caller.clone()), 
             amount, 
-            purpose
+            purpose,
+            category,
+            T::BenchmarkHelper::asset(),
+            caller.clone(),
+            None,
+            PaymentSchedule::LumpSum
         );
 
         // Verification: Check that proposal was created
@@ -31,67 +182,769 @@ mod benchmarks {
         assert!(BudgetProposals::<T>::get(0).is_some());
     }
     #[benchmark]
-    fn approve_proposal() {
+    fn approve_proposal() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ApproverOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
         let creator: T::AccountId = whitelisted_caller();
-        let approver: T::AccountId = whitelisted_caller(); // Different account
+        let category = setup_category::<T>();
         
         // Setup: First create a proposal to approve
         assert_ok!(BudgetProposalPallet::<T>::create_proposal(
             RawOrigin::Signed(creator.clone()).into(),
-            1000u128,
-            b"Infrastructure upgrade".to_vec()
+            1000u32.into(),
+            b"Infrastructure upgrade".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
         ));
+        endorse::<T>(0);
 
         #[extrinsic_call]
-        approve_proposal(RawOrigin::Signed(approver.clone()), 0);
+        approve_proposal(origin as T::RuntimeOrigin, 0);
 
-        // Verification: Check that proposal status changed to Approved
+        // Verification: Check that proposal is waiting out its challenge period
         let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
-        assert_eq!(proposal.status, ProposalStatus::Approved);
+        assert_eq!(proposal.status, ProposalStatus::AwaitingExecution);
+        Ok(())
     }
     #[benchmark]
-    fn reject_proposal() {
+    fn reject_proposal() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ApproverOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
         let creator: T::AccountId = whitelisted_caller();
-        let rejecter: T::AccountId = whitelisted_caller(); // Different account
+        let category = setup_category::<T>();
         
         // Setup: First create a proposal to reject
         assert_ok!(BudgetProposalPallet::<T>::create_proposal(
             RawOrigin::Signed(creator.clone()).into(),
-            2000u128,
-            b"Marketing campaign".to_vec()
+            2000u32.into(),
+            b"Marketing campaign".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
         ));
+        endorse::<T>(0);
 
         // Prepare maximum size reason (100 bytes)
         let reason = b"X".repeat(100);
 
         #[extrinsic_call]
-        reject_proposal(RawOrigin::Signed(rejecter.clone()), 0, reason);
+        reject_proposal(origin as T::RuntimeOrigin, 0, reason);
 
         // Verification: Check that proposal status changed to Rejected
         let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
         assert_eq!(proposal.status, ProposalStatus::Rejected);
+        Ok(())
     }
     #[benchmark]
     fn create_proposal_with_max_sizes() {
         let caller: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
         
         // Setup: Prepare maximum size data
-        let amount = u128::MAX; // Maximum possible amount
+        let amount = T::Balance::max_value(); // Maximum possible amount
         let purpose = b"A".repeat(200); // Maximum allowed purpose size
 
         #[extrinsic_call]
         create_proposal(
             RawOrigin::Signed(caller.clone()), 
             amount, 
-            purpose
+            purpose,
+            category,
+            T::BenchmarkHelper::asset(),
+            caller.clone(),
+            None,
+            PaymentSchedule::LumpSum
         );
 
         // Verification: Check that proposal was created with max data
         assert_eq!(NextProposalId::<T>::get(), 1);
         let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
-        assert_eq!(proposal.amount, u128::MAX);
+        assert_eq!(proposal.amount, T::Balance::max_value());
         assert_eq!(proposal.purpose.len(), 200);
-        assert_eq!(proposal.status, ProposalStatus::Pending);
+        assert!(matches!(
+            proposal.status,
+            ProposalStatus::SeekingEndorsements |
+                ProposalStatus::Pending |
+                ProposalStatus::UnderReferendum
+        ));
+    }
+    #[benchmark]
+    fn update_proposal() {
+        let creator: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
+
+        // Setup: First create a proposal to update
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            1000u32.into(),
+            b"Road repairs".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(0);
+
+        // Prepare maximum size purpose (200 bytes)
+        let purpose = b"B".repeat(200);
+        let amount: T::Balance = 1500u32.into();
+
+        #[extrinsic_call]
+        update_proposal(RawOrigin::Signed(creator.clone()), 0, amount, purpose);
+
+        // Verification: Check that proposal was updated and revision recorded
+        let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
+        assert_eq!(proposal.amount, amount);
+        assert_eq!(ProposalRevisions::<T>::get(0).len(), 1);
+    }
+    #[benchmark]
+    fn withdraw_proposal() {
+        let creator: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
+
+        // Setup: First create a proposal to withdraw
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            1000u32.into(),
+            b"Road repairs".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        #[extrinsic_call]
+        withdraw_proposal(RawOrigin::Signed(creator.clone()), 0);
+
+        // Verification: Check that proposal status changed to Withdrawn
+        let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
+        assert_eq!(proposal.status, ProposalStatus::Withdrawn);
+    }
+    #[benchmark]
+    fn expire_proposals(n: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+        let creator: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
+
+        // Setup: Create `n` proposals that all expire in the same block
+        for _ in 0..n {
+            assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+                RawOrigin::Signed(creator.clone()).into(),
+                1000u32.into(),
+                b"Road repairs".to_vec(),
+                category,
+                T::BenchmarkHelper::asset(),
+                creator.clone(),
+                None,
+                PaymentSchedule::LumpSum
+            ));
+        }
+        let now: u64 = frame_system::Pallet::<T>::block_number()
+            .try_into()
+            .ok()
+            .expect("block number fits in u64");
+        let expires_at = now + T::ProposalLifetime::get();
+        let block: BlockNumberFor<T> = expires_at.try_into().ok().expect("block number fits");
+
+        #[block]
+        {
+            BudgetProposalPallet::<T>::on_initialize(block);
+        }
+
+        // Verification: Check that the queue was drained and proposals expired
+        assert!(!ProposalExpiries::<T>::contains_prefix(expires_at));
+        if n > 0 {
+            let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
+            assert_eq!(proposal.status, ProposalStatus::Expired);
+        }
+    }
+    #[benchmark]
+    fn set_budget_ceiling() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let asset = T::BenchmarkHelper::asset();
+        let ceiling: T::Balance = 1_000_000u32.into();
+
+        #[extrinsic_call]
+        set_budget_ceiling(origin as T::RuntimeOrigin, 0, asset.clone(), ceiling);
+
+        // Verification: Check that the ceiling was stored
+        assert_eq!(FiscalBudgets::<T>::get(0, asset).ceiling, Some(ceiling));
+        Ok(())
+    }
+    #[benchmark]
+    fn register_category() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let category = NextCategoryId::<T>::get();
+
+        // Prepare maximum size name (50 bytes)
+        let name = b"C".repeat(50);
+
+        #[extrinsic_call]
+        register_category(origin as T::RuntimeOrigin, name);
+
+        // Verification: Check that the category was registered
+        assert!(BudgetCategories::<T>::contains_key(category));
+        Ok(())
+    }
+    #[benchmark]
+    fn set_category_allocation() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let category = setup_category::<T>();
+        let asset = T::BenchmarkHelper::asset();
+        let allocation: T::Balance = 1_000_000u32.into();
+
+        #[extrinsic_call]
+        set_category_allocation(
+            origin as T::RuntimeOrigin,
+            0,
+            category,
+            asset.clone(),
+            allocation
+        );
+
+        // Verification: Check that the allocation was stored
+        assert_eq!(CategoryBudgets::<T>::get((0, category, asset)).ceiling, Some(allocation));
+        Ok(())
+    }
+    #[benchmark]
+    fn execute_proposal() {
+        let creator: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let category = setup_category::<T>();
+        let asset = T::BenchmarkHelper::asset();
+
+        // Setup: Fund the treasury and approve a lump-sum proposal
+        let amount = T::Assets::minimum_balance(asset.clone()).max(1u32.into()) * 10u32.into();
+        assert_ok!(T::Assets::mint_into(
+            asset.clone(),
+            &BudgetProposalPallet::<T>::treasury_account(),
+            amount * 10u32.into()
+        ));
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            amount,
+            b"Bridge construction".to_vec(),
+            category,
+            asset.clone(),
+            beneficiary.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(0);
+        approve::<T>(0);
+        let executable_at = BudgetProposals::<T>::get(0)
+            .and_then(|proposal| proposal.executable_at)
+            .expect("Approved proposal has an execution block");
+        frame_system::Pallet::<T>::set_block_number(
+            executable_at.try_into().ok().expect("block number fits"),
+        );
+
+        #[extrinsic_call]
+        execute_proposal(RawOrigin::Signed(creator.clone()), 0);
+
+        // Verification: Check that the beneficiary was paid in full
+        let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+        assert_eq!(T::Assets::balance(asset, &beneficiary), amount);
+    }
+    #[benchmark]
+    fn veto_proposal() -> Result<(), BenchmarkError> {
+        let origin =
+            T::OversightOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let creator: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
+
+        // Setup: Approve a proposal so it enters its challenge period
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            1000u32.into(),
+            b"Infrastructure upgrade".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(0);
+        approve::<T>(0);
+
+        // Prepare maximum size reason (100 bytes)
+        let reason = b"X".repeat(100);
+
+        #[extrinsic_call]
+        veto_proposal(origin as T::RuntimeOrigin, 0, reason);
+
+        // Verification: Check that proposal status changed to Vetoed
+        let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
+        assert_eq!(proposal.status, ProposalStatus::Vetoed);
+        Ok(())
+    }
+    #[benchmark]
+    fn create_recurring_line() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let category = setup_category::<T>();
+
+        #[extrinsic_call]
+        create_recurring_line(
+            origin as T::RuntimeOrigin,
+            beneficiary,
+            T::BenchmarkHelper::asset(),
+            1000u32.into(),
+            category,
+            10,
+            10,
+            1_000
+        );
+
+        // Verification: Check that the line was stored and its first payment scheduled
+        assert!(RecurringLines::<T>::contains_key(0));
+        assert_eq!(RecurringPayments::<T>::get(10).len(), 1);
+        Ok(())
+    }
+    #[benchmark]
+    fn resume_recurring_line() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let category = setup_category::<T>();
+        let line_id = setup_recurring_line::<T>(origin.clone(), category, 1000u32.into())?;
+
+        // Setup: Pause the line
+        RecurringLines::<T>::mutate(line_id, |line| {
+            if let Some(line) = line {
+                line.status = RecurringLineStatus::Paused;
+            }
+        });
+
+        #[extrinsic_call]
+        resume_recurring_line(origin as T::RuntimeOrigin, line_id);
+
+        // Verification: Check that the line is active again
+        let line = RecurringLines::<T>::get(line_id).expect("Line should exist");
+        assert_eq!(line.status, RecurringLineStatus::Active);
+        Ok(())
+    }
+    #[benchmark]
+    fn cancel_recurring_line() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let category = setup_category::<T>();
+        let line_id = setup_recurring_line::<T>(origin.clone(), category, 1000u32.into())?;
+
+        #[extrinsic_call]
+        cancel_recurring_line(origin as T::RuntimeOrigin, line_id);
+
+        // Verification: Check that the line was cancelled
+        let line = RecurringLines::<T>::get(line_id).expect("Line should exist");
+        assert_eq!(line.status, RecurringLineStatus::Cancelled);
+        Ok(())
+    }
+    #[benchmark]
+    fn pay_recurring_lines(
+        n: Linear<0, { T::MaxRecurringPaymentsPerBlock::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let category = setup_category::<T>();
+        let asset = T::BenchmarkHelper::asset();
+
+        // Setup: Fund the treasury and create `n` lines all due in the next block
+        let amount = T::Assets::minimum_balance(asset.clone()).max(1u32.into()) * 10u32.into();
+        assert_ok!(T::Assets::mint_into(
+            asset,
+            &BudgetProposalPallet::<T>::treasury_account(),
+            amount * (n + 1).into()
+        ));
+        for _ in 0..n {
+            setup_recurring_line::<T>(origin.clone(), category, amount)?;
+        }
+        let now: u64 = frame_system::Pallet::<T>::block_number()
+            .try_into()
+            .ok()
+            .expect("block number fits in u64");
+        let block: BlockNumberFor<T> = (now + 1).try_into().ok().expect("block number fits");
+
+        #[block]
+        {
+            BudgetProposalPallet::<T>::on_initialize(block);
+        }
+
+        // Verification: Check that every line was paid and rescheduled
+        assert!(RecurringPayments::<T>::get(now + 1).is_empty());
+        assert_eq!(RecurringPayments::<T>::get(now + 11).len() as u32, n);
+        Ok(())
+    }
+    #[benchmark]
+    fn submit_expense_report(n: Linear<1, { T::MaxExpenseItems::get() }>) {
+        let beneficiary: T::AccountId = whitelisted_caller();
+        let proposal_id = setup_approved_proposals::<T>(1, &beneficiary)[0];
+        assert_ok!(BudgetProposalPallet::<T>::execute_proposal(
+            RawOrigin::Signed(beneficiary.clone()).into(),
+            proposal_id
+        ));
+
+        // Prepare `n` items of maximum size that together stay within the disbursed amount
+        let items: Vec<_> = (0..n)
+            .map(|_| ExpenseItem {
+                amount: 1u32.into(),
+                receipt_hash: [7u8; 32],
+                description: BoundedVec::truncate_from(b"D".repeat(100)),
+            })
+            .collect();
+
+        #[extrinsic_call]
+        submit_expense_report(RawOrigin::Signed(beneficiary.clone()), proposal_id, items);
+
+        // Verification: Check that the items were stored
+        assert_eq!(ExpenseReports::<T>::get(proposal_id).len() as u32, n);
+    }
+    #[benchmark]
+    fn check_reporting_deadlines(n: Linear<0, { T::MaxReportingDeadlinesPerBlock::get() }>) {
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let proposal_ids = setup_approved_proposals::<T>(n, &beneficiary);
+        for proposal_id in &proposal_ids {
+            assert_ok!(BudgetProposalPallet::<T>::execute_proposal(
+                RawOrigin::Signed(beneficiary.clone()).into(),
+                *proposal_id
+            ));
+        }
+        let now: u64 = frame_system::Pallet::<T>::block_number()
+            .try_into()
+            .ok()
+            .expect("block number fits in u64");
+        let deadline = now + T::ReportingPeriod::get();
+        let block: BlockNumberFor<T> = deadline.try_into().ok().expect("block number fits");
+
+        #[block]
+        {
+            BudgetProposalPallet::<T>::on_initialize(block);
+        }
+
+        // Verification: Check that every unreported proposal was flagged
+        assert!(!ReportingDeadlines::<T>::contains_prefix(deadline));
+        for proposal_id in proposal_ids {
+            assert!(Reconciliations::<T>::get(proposal_id).flagged);
+        }
+    }
+    #[benchmark]
+    fn claw_back() -> Result<(), BenchmarkError> {
+        let origin = T::EnforcementOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let proposal_id = setup_approved_proposals::<T>(1, &beneficiary)[0];
+        assert_ok!(BudgetProposalPallet::<T>::execute_proposal(
+            RawOrigin::Signed(beneficiary.clone()).into(),
+            proposal_id
+        ));
+
+        // Fill the clawback history up to its last free slot
+        let record = ClawbackRecord {
+            amount: 1u32.into(),
+            reason: BoundedVec::truncate_from(b"R".repeat(100)),
+            at: 0,
+        };
+        let history: Vec<_> = (1..T::MaxClawbacks::get()).map(|_| record.clone()).collect();
+        Clawbacks::<T>::insert(proposal_id, BoundedVec::truncate_from(history));
+        let reason = b"X".repeat(100);
+
+        #[extrinsic_call]
+        claw_back(origin as T::RuntimeOrigin, proposal_id, 1u32.into(), reason);
+
+        // Verification: Check that the clawback was recorded
+        assert_eq!(
+            BudgetProposals::<T>::get(proposal_id).unwrap().status,
+            ProposalStatus::ClawedBack
+        );
+        assert_eq!(Clawbacks::<T>::get(proposal_id).len() as u32, T::MaxClawbacks::get());
+        Ok(())
+    }
+    #[benchmark]
+    fn open_round() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let pot: T::Balance = 1_000_000u32.into();
+
+        #[extrinsic_call]
+        open_round(
+            origin as T::RuntimeOrigin,
+            pot,
+            T::BenchmarkHelper::asset(),
+            RoundVoting::Points(10),
+            100
+        );
+
+        // Verification: Check that the round was stored
+        assert_eq!(Rounds::<T>::get(0).unwrap().pot, pot);
+        Ok(())
+    }
+    #[benchmark]
+    fn enter_round() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let creator: T::AccountId = whitelisted_caller();
+
+        // Setup: Fill the round up to its last free slot and create one more proposal
+        let (round_id, _) = setup_round::<T>(origin, T::MaxRoundProposals::get() - 1)?;
+        let category = setup_category::<T>();
+        let proposal_id = NextProposalId::<T>::get();
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            1000u32.into(),
+            b"Street lighting".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(proposal_id);
+
+        #[extrinsic_call]
+        enter_round(RawOrigin::Signed(creator), round_id, proposal_id);
+
+        // Verification: Check that the proposal was entered
+        assert_eq!(
+            BudgetProposals::<T>::get(proposal_id).unwrap().status,
+            ProposalStatus::InRound
+        );
+        assert_eq!(RoundProposals::<T>::get(round_id).len() as u32, T::MaxRoundProposals::get());
+        Ok(())
+    }
+    #[benchmark]
+    fn allocate_votes(
+        n: Linear<1, { T::MaxRoundProposals::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let voter: T::AccountId = account("voter", 0, 0);
+        T::Citizens::register(&voter);
+        let (round_id, proposal_ids) = setup_round::<T>(origin, n)?;
+
+        // Setup: Cast an earlier ballot of the same size to be replaced
+        let ballot: Vec<(u64, u32)> = proposal_ids.iter().map(|id| (*id, 1)).collect();
+        assert_ok!(BudgetProposalPallet::<T>::allocate_votes(
+            RawOrigin::Signed(voter.clone()).into(),
+            round_id,
+            ballot.clone()
+        ));
+
+        #[extrinsic_call]
+        allocate_votes(RawOrigin::Signed(voter.clone()), round_id, ballot);
+
+        // Verification: Check that each proposal kept a single vote
+        for proposal_id in proposal_ids {
+            assert_eq!(RoundScores::<T>::get(round_id, proposal_id), 1);
+        }
+        Ok(())
+    }
+    #[benchmark]
+    fn close_round(n: Linear<0, { T::MaxRoundProposals::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        T::Citizens::register(&caller);
+        let (round_id, proposal_ids) = setup_round::<T>(origin, n)?;
+
+        // Setup: Vote for every proposal and move past the end of voting
+        let ballot: Vec<(u64, u32)> = proposal_ids.iter().map(|id| (*id, 1)).collect();
+        if !ballot.is_empty() {
+            assert_ok!(BudgetProposalPallet::<T>::allocate_votes(
+                RawOrigin::Signed(caller.clone()).into(),
+                round_id,
+                ballot
+            ));
+        }
+        let closes_at = Rounds::<T>::get(round_id).unwrap().closes_at;
+        frame_system::Pallet::<T>::set_block_number(
+            closes_at.try_into().ok().expect("block number fits"),
+        );
+
+        #[extrinsic_call]
+        close_round(RawOrigin::Signed(caller), round_id);
+
+        // Verification: Check that every proposal was funded
+        assert!(Rounds::<T>::get(round_id).unwrap().closed);
+        for proposal_id in proposal_ids {
+            assert_eq!(
+                BudgetProposals::<T>::get(proposal_id).unwrap().status,
+                ProposalStatus::AwaitingExecution
+            );
+        }
+        Ok(())
+    }
+    #[benchmark]
+    fn endorse_proposal() {
+        let creator: T::AccountId = whitelisted_caller();
+        let endorser: T::AccountId = account("endorser", 0, 0);
+        let category = setup_category::<T>();
+        let amount: T::Balance = 1000u32.into();
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            amount,
+            b"Community development project".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Setup: Hold the proposal one endorsement short of review
+        BudgetProposals::<T>::mutate(0, |proposal| {
+            if let Some(proposal) = proposal {
+                proposal.status = ProposalStatus::SeekingEndorsements;
+            }
+        });
+        let required = BudgetProposalPallet::<T>::required_endorsements(amount);
+        let endorsers: Vec<T::AccountId> =
+            (1..required).map(|i| account("endorser", i, 0)).collect();
+        Endorsements::<T>::insert(0, BoundedVec::truncate_from(endorsers));
+
+        #[extrinsic_call]
+        endorse_proposal(RawOrigin::Signed(endorser.clone()), 0);
+
+        // Verification: Check that the proposal left the endorsement phase
+        assert!(Endorsements::<T>::get(0).contains(&endorser));
+        assert_ne!(
+            BudgetProposals::<T>::get(0).unwrap().status,
+            ProposalStatus::SeekingEndorsements
+        );
+    }
+    #[benchmark]
+    fn declare_conflict() {
+        let approver: T::AccountId = whitelisted_caller();
+        let related: T::AccountId = account("related", 0, 0);
+
+        #[extrinsic_call]
+        declare_conflict(
+            RawOrigin::Signed(approver.clone()),
+            related.clone(),
+            Relationship::Family
+        );
+
+        // Verification: Check that the declaration was stored
+        assert!(BudgetProposalPallet::<T>::has_conflict(&approver, &related));
+    }
+    #[benchmark]
+    fn set_conflict() -> Result<(), BenchmarkError> {
+        let origin =
+            T::EthicsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let approver: T::AccountId = account("approver", 0, 0);
+        let related: T::AccountId = account("related", 0, 0);
+
+        #[extrinsic_call]
+        set_conflict(
+            origin as T::RuntimeOrigin,
+            approver.clone(),
+            related.clone(),
+            Relationship::Business
+        );
+
+        // Verification: Check that the declaration was stored
+        assert_eq!(
+            ConflictDeclarations::<T>::get(&approver, &related).unwrap().declared_by,
+            DeclarationSource::Ethics
+        );
+        Ok(())
+    }
+    #[benchmark]
+    fn remove_conflict() -> Result<(), BenchmarkError> {
+        let origin =
+            T::EthicsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let approver: T::AccountId = account("approver", 0, 0);
+        let related: T::AccountId = account("related", 0, 0);
+        assert_ok!(BudgetProposalPallet::<T>::declare_conflict(
+            RawOrigin::Signed(approver.clone()).into(),
+            related.clone(),
+            Relationship::Family
+        ));
+
+        #[extrinsic_call]
+        remove_conflict(origin as T::RuntimeOrigin, approver.clone(), related.clone());
+
+        // Verification: Check that the declaration was removed
+        assert!(!BudgetProposalPallet::<T>::has_conflict(&approver, &related));
+        Ok(())
+    }
+    #[benchmark]
+    fn recuse() {
+        let creator: T::AccountId = whitelisted_caller();
+        let approver: T::AccountId = account("approver", 0, 0);
+        let category = setup_category::<T>();
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            1000u32.into(),
+            b"Infrastructure upgrade".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        endorse::<T>(0);
+
+        #[extrinsic_call]
+        recuse(RawOrigin::Signed(approver.clone()), 0);
+
+        // Verification: Check that the recusal was recorded
+        assert!(Recusals::<T>::contains_key(0, &approver));
+    }
+    #[benchmark]
+    fn fund_wallet() {
+        let wallet = T::BenchmarkHelper::wallet();
+        let asset = T::BenchmarkHelper::asset();
+        let amount = T::Assets::minimum_balance(asset.clone()).max(1u32.into()) * 10u32.into();
+
+        // Setup: Give the wallet enough to move the funds and stay alive
+        assert_ok!(T::Assets::mint_into(asset.clone(), &wallet, amount * 2u32.into()));
+
+        #[extrinsic_call]
+        fund_wallet(RawOrigin::Signed(wallet.clone()), asset.clone(), amount);
+
+        // Verification: Check that the funds reached the wallet's payment account
+        assert_eq!(
+            T::Assets::balance(asset, &BudgetProposalPallet::<T>::wallet_account(&wallet)),
+            amount
+        );
+    }
+    #[benchmark]
+    fn conclude_referendum() {
+        let creator: T::AccountId = whitelisted_caller();
+        let category = setup_category::<T>();
+        assert_ok!(BudgetProposalPallet::<T>::create_proposal(
+            RawOrigin::Signed(creator.clone()).into(),
+            1000u32.into(),
+            b"Infrastructure upgrade".to_vec(),
+            category,
+            T::BenchmarkHelper::asset(),
+            creator.clone(),
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        BudgetProposals::<T>::mutate(0, |proposal| {
+            if let Some(proposal) = proposal {
+                proposal.status = ProposalStatus::UnderReferendum;
+            }
+        });
+
+        #[block]
+        {
+            assert_ok!(BudgetProposalPallet::<T>::conclude_referendum(0, true));
+        }
+
+        // Verification: Check that the passed referendum approved the proposal
+        let proposal = BudgetProposals::<T>::get(0).expect("Proposal should exist");
+        assert_eq!(proposal.status, ProposalStatus::AwaitingExecution);
     }
     impl_benchmark_test_suite!(
         BudgetProposalPallet, 
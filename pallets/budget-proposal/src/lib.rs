@@ -1,17 +1,102 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+use frame::prelude::{DispatchError, DispatchResult};
 use scale_info::prelude::vec::Vec;
 
+pub mod migrations;
+pub mod runtime_api;
 pub mod weights;
 use crate::weights::WeightInfo;
 
+/// Maximum number of proposals returned by a single `Pallet::proposals` query.
+pub const MAX_PROPOSALS_PAGE: u32 = 100;
+
+/// Information about government wallets that proposals can be paid from.
+///
+/// The funds a wallet spends on proposals are held in the pallet's account for it, see
+/// `Pallet::wallet_account`, which the wallet tops up with `Pallet::fund_wallet`. Every payment
+/// is debited from the wallet's ledger.
+pub trait GovernmentWallets<AccountId, Balance> {
+    /// Whether `wallet` is a registered government wallet.
+    fn wallet_exists(wallet: &AccountId) -> bool;
+
+    /// Debit `amount` paid on behalf of `wallet` from its ledger.
+    fn debit(wallet: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+impl<AccountId, Balance> GovernmentWallets<AccountId, Balance> for () {
+    fn wallet_exists(_wallet: &AccountId) -> bool {
+        false
+    }
+
+    fn debit(_wallet: &AccountId, _amount: Balance) -> DispatchResult {
+        Err(DispatchError::Other("No government wallets"))
+    }
+}
+
+/// Puts budget proposals to a community referendum.
+///
+/// The provider reports the outcome back through `Pallet::conclude_referendum`.
+pub trait ReferendumProvider {
+    /// Open a referendum on `proposal_id`.
+    fn open_referendum(proposal_id: u64) -> DispatchResult;
+}
+
+impl ReferendumProvider for () {
+    fn open_referendum(_proposal_id: u64) -> DispatchResult {
+        Err(DispatchError::Other("No referendum provider"))
+    }
+}
+
+/// Information about the citizens allowed to vote in participatory budgeting rounds.
+pub trait CitizenRegistry<AccountId> {
+    /// Whether `who` may allocate votes in a round.
+    fn is_eligible(who: &AccountId) -> bool;
+
+    /// Make `who` eligible to allocate votes.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register(who: &AccountId);
+}
+
+impl<AccountId> CitizenRegistry<AccountId> for () {
+    fn is_eligible(_who: &AccountId) -> bool {
+        false
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register(_who: &AccountId) {}
+}
+
+/// Helper for benchmarks to provide an asset that proposals can request and a wallet that can
+/// pay them.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+    /// An asset that exists in `Config::Assets`.
+    fn asset() -> AssetId;
+
+    /// A registered government wallet, created if needed.
+    fn wallet() -> AccountId;
+}
+
 #[frame::pallet]
 pub mod pallet {
     use super::*;
-    use frame::prelude::*;
+    use frame::{
+        deps::frame_support::PalletId,
+        prelude::*,
+        traits::{
+            fungibles::{self, Inspect, Mutate},
+            tokens::{Fortitude, Preservation},
+            AccountIdConversion, AtLeast32BitUnsigned, Zero,
+        },
+    };
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // Configuration trait for the pallet.
@@ -21,6 +106,119 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type WeightInfo: WeightInfo;
+
+        /// The balance type used for proposal amounts and budgets.
+        type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+
+        /// Identifier of an asset held by the treasury, such as the native token or a stablecoin.
+        type AssetId: Parameter + Member + MaxEncodedLen;
+
+        /// The assets proposals can request funds in.
+        type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
+            + fungibles::Mutate<Self::AccountId>;
+
+        /// Helper to provide an existing asset in benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId>;
+
+        /// Maximum number of revisions kept for a single proposal.
+        #[pallet::constant]
+        type MaxRevisions: Get<u32>;
+
+        /// Number of blocks a proposal may wait for a decision before it expires.
+        #[pallet::constant]
+        type ProposalLifetime: Get<u64>;
+
+        /// Maximum number of proposals expired in one block; the rest are carried over.
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+
+        /// Origin allowed to set the budget ceiling of a fiscal period.
+        type BudgetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Length of a fiscal period in blocks. Period `n` covers blocks
+        /// `[n * FiscalPeriodLength, (n + 1) * FiscalPeriodLength)`.
+        #[pallet::constant]
+        type FiscalPeriodLength: Get<u64>;
+
+        /// Maximum number of recurring line payments that can be scheduled in one block.
+        #[pallet::constant]
+        type MaxRecurringPaymentsPerBlock: Get<u32>;
+
+        /// Number of blocks the beneficiary of an executed proposal has to report what the
+        /// funds were spent on.
+        #[pallet::constant]
+        type ReportingPeriod: Get<u64>;
+
+        /// Maximum number of expense items reported against a single proposal.
+        #[pallet::constant]
+        type MaxExpenseItems: Get<u32>;
+
+        /// Maximum number of reporting deadlines checked in one block; the rest are carried over.
+        #[pallet::constant]
+        type MaxReportingDeadlinesPerBlock: Get<u32>;
+
+        /// Opens the community referendums that decide large proposals.
+        type Referenda: ReferendumProvider;
+
+        /// Proposals requesting more than this amount are decided by referendum instead of
+        /// by an approver.
+        #[pallet::constant]
+        type ReferendumThreshold: Get<Self::Balance>;
+
+        /// The government wallets a proposal may name as its paying source.
+        type Wallets: GovernmentWallets<Self::AccountId, Self::Balance>;
+
+        /// Origin allowed to approve and reject proposals, such as a budget committee member,
+        /// resolving to the deciding account.
+        type ApproverOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Number of blocks an approved proposal waits before it can be executed, during
+        /// which `OversightOrigin` may veto it.
+        #[pallet::constant]
+        type ChallengePeriod: Get<u64>;
+
+        /// Origin allowed to veto approved proposals during their challenge period, such as
+        /// the auditor-general or a court.
+        type OversightOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to claw back unspent or misused funds from paid proposals, such as
+        /// an anti-corruption commission.
+        type EnforcementOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of clawbacks recorded against a single proposal.
+        #[pallet::constant]
+        type MaxClawbacks: Get<u32>;
+
+        /// The citizens allowed to vote in participatory budgeting rounds.
+        type Citizens: CitizenRegistry<Self::AccountId>;
+
+        /// Maximum number of proposals entered into a single participatory budgeting round.
+        #[pallet::constant]
+        type MaxRoundProposals: Get<u32>;
+
+        /// Number of endorsements every proposal needs before it is reviewed.
+        #[pallet::constant]
+        type BaseEndorsements: Get<u32>;
+
+        /// Amount requested per additional endorsement a proposal needs. Zero disables the
+        /// scaling.
+        #[pallet::constant]
+        type EndorsementStep: Get<Self::Balance>;
+
+        /// Maximum number of endorsements a proposal can need, and the number of endorsers
+        /// stored for it.
+        #[pallet::constant]
+        type MaxEndorsements: Get<u32>;
+
+        /// Origin allowed to declare and remove conflicts of interest on behalf of approvers,
+        /// such as an ethics commission.
+        type EthicsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The treasury's pallet ID, used to derive the account paying proposals that do not
+        /// name a government wallet.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
     }
 
     #[pallet::event]
@@ -32,10 +230,16 @@ pub mod pallet {
             proposal_id: u64,
             /// The creator of the proposal.
             creator: T::AccountId,
+            /// The account that receives the funds.
+            beneficiary: T::AccountId,
             /// The amount requested.
-            amount: u128,
+            amount: T::Balance,
             /// The purpose of the proposal.
             purpose: BoundedVec<u8, ConstU32<200>>,
+            /// The budget category the proposal draws on.
+            category: u32,
+            /// The asset the amount is requested in.
+            asset: T::AssetId,
         },
         /// A budget proposal was approved.
         BudgetProposalApproved {
@@ -53,6 +257,272 @@ pub mod pallet {
             /// The reason for rejection.
             reason: BoundedVec<u8, ConstU32<100>>,
         },
+        /// A pending budget proposal was edited by its creator.
+        BudgetProposalUpdated {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The new amount requested.
+            amount: T::Balance,
+            /// The new purpose of the proposal.
+            purpose: BoundedVec<u8, ConstU32<200>>,
+            /// The number of revisions now recorded for the proposal.
+            revision: u32,
+        },
+        /// A pending budget proposal was withdrawn by its creator.
+        BudgetProposalWithdrawn {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The creator of the proposal.
+            creator: T::AccountId,
+        },
+        /// A pending budget proposal expired without a decision.
+        BudgetProposalExpired {
+            /// The ID of the proposal.
+            proposal_id: u64,
+        },
+        /// The budget ceiling of a fiscal period was set.
+        BudgetCeilingSet {
+            /// The fiscal period.
+            period: u32,
+            /// The asset the ceiling applies to.
+            asset: T::AssetId,
+            /// The total amount that may be approved in the period.
+            ceiling: T::Balance,
+        },
+        /// A new budget category was registered.
+        BudgetCategoryRegistered {
+            /// The ID of the category.
+            category: u32,
+            /// The name of the category.
+            name: BoundedVec<u8, ConstU32<50>>,
+        },
+        /// The allocation of a budget category for a fiscal period was set.
+        CategoryAllocationSet {
+            /// The fiscal period.
+            period: u32,
+            /// The ID of the category.
+            category: u32,
+            /// The asset the allocation applies to.
+            asset: T::AssetId,
+            /// The total amount that may be approved for the category in the period.
+            allocation: T::Balance,
+        },
+        /// A payment was made to the beneficiary of an approved proposal.
+        ProposalPaymentExecuted {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The account the payment was made from.
+            source: T::AccountId,
+            /// The account that received the payment.
+            beneficiary: T::AccountId,
+            /// The amount paid.
+            amount: T::Balance,
+        },
+        /// An approved proposal was paid out in full.
+        BudgetProposalExecuted {
+            /// The ID of the proposal.
+            proposal_id: u64,
+        },
+        /// An account endorsed a proposal that is seeking endorsements.
+        ProposalEndorsed {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The account that endorsed the proposal.
+            endorser: T::AccountId,
+            /// The number of endorsements the proposal has.
+            endorsements: u32,
+            /// The number of endorsements the proposal needs.
+            required: u32,
+        },
+        /// A proposal gathered the endorsements it needs and was sent for review.
+        ProposalEndorsementsReached {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The accounts that endorsed the proposal.
+            endorsers: BoundedVec<T::AccountId, T::MaxEndorsements>,
+        },
+        /// A conflict of interest between an approver and another account was declared.
+        ConflictDeclared {
+            /// The approver with the conflict.
+            approver: T::AccountId,
+            /// The account the approver is related to.
+            related: T::AccountId,
+            /// How the two accounts are related.
+            relationship: Relationship,
+            /// Who made the declaration.
+            declared_by: DeclarationSource,
+        },
+        /// A declared conflict of interest was removed.
+        ConflictRemoved {
+            /// The approver with the conflict.
+            approver: T::AccountId,
+            /// The account the approver was related to.
+            related: T::AccountId,
+        },
+        /// An approver recused themselves from deciding a proposal.
+        ApproverRecused {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The approver who recused themselves.
+            approver: T::AccountId,
+        },
+        /// A proposal was put to a community referendum.
+        BudgetProposalReferendumOpened {
+            /// The ID of the proposal.
+            proposal_id: u64,
+        },
+        /// The referendum on a proposal concluded and the proposal was approved or rejected.
+        BudgetProposalReferendumConcluded {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// Whether the proposal was approved.
+            approved: bool,
+        },
+        /// A recurring budget line was approved.
+        RecurringLineCreated {
+            /// The ID of the recurring line.
+            line_id: u32,
+            /// The account that receives the payments.
+            beneficiary: T::AccountId,
+            /// The amount paid every interval.
+            amount: T::Balance,
+            /// The number of blocks between payments.
+            interval: u64,
+        },
+        /// A payment of a recurring budget line was made from the treasury.
+        RecurringPaymentMade {
+            /// The ID of the recurring line.
+            line_id: u32,
+            /// The account that received the payment.
+            beneficiary: T::AccountId,
+            /// The amount paid.
+            amount: T::Balance,
+        },
+        /// A payment of a recurring budget line could not be made.
+        RecurringPaymentFailed {
+            /// The ID of the recurring line.
+            line_id: u32,
+            /// Why the payment failed.
+            reason: RecurringPaymentFailure,
+        },
+        /// A recurring budget line was paused after a failed payment.
+        RecurringLinePaused {
+            /// The ID of the recurring line.
+            line_id: u32,
+        },
+        /// A paused recurring budget line was resumed.
+        RecurringLineResumed {
+            /// The ID of the recurring line.
+            line_id: u32,
+            /// The block of the next payment.
+            next_payment: u64,
+        },
+        /// A recurring budget line made its last payment.
+        RecurringLineEnded {
+            /// The ID of the recurring line.
+            line_id: u32,
+        },
+        /// A recurring budget line was cancelled.
+        RecurringLineCancelled {
+            /// The ID of the recurring line.
+            line_id: u32,
+        },
+        /// The beneficiary of a proposal reported expenses against it.
+        ExpenseReportSubmitted {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The number of items in the report.
+            items: u32,
+            /// The total amount of the report.
+            amount: T::Balance,
+            /// The total reported against the proposal so far.
+            reported: T::Balance,
+        },
+        /// The expenses reported against an executed proposal match the amount disbursed.
+        ProposalReconciled {
+            /// The ID of the proposal.
+            proposal_id: u64,
+        },
+        /// The reporting deadline of a proposal passed with part of the disbursed amount
+        /// unaccounted for.
+        ProposalUnreconciled {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The amount paid to the beneficiary.
+            disbursed: T::Balance,
+            /// The amount covered by expense reports.
+            reported: T::Balance,
+        },
+        /// An approved proposal was vetoed during its challenge period.
+        BudgetProposalVetoed {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The reason for the veto.
+            reason: BoundedVec<u8, ConstU32<100>>,
+        },
+        /// Funds of a paid proposal were clawed back into the treasury.
+        FundsClawedBack {
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The account the funds were recovered from.
+            beneficiary: T::AccountId,
+            /// The amount recovered.
+            amount: T::Balance,
+            /// The reason for the clawback.
+            reason: BoundedVec<u8, ConstU32<100>>,
+        },
+        /// A participatory budgeting round was opened.
+        RoundOpened {
+            /// The ID of the round.
+            round_id: u32,
+            /// The amount the round can fund.
+            pot: T::Balance,
+            /// The block at which voting closes.
+            closes_at: u64,
+        },
+        /// A proposal was entered into a participatory budgeting round.
+        ProposalEnteredRound {
+            /// The ID of the round.
+            round_id: u32,
+            /// The ID of the proposal.
+            proposal_id: u64,
+        },
+        /// A citizen allocated their votes in a round, replacing any earlier ballot.
+        VotesAllocated {
+            /// The ID of the round.
+            round_id: u32,
+            /// The citizen who voted.
+            voter: T::AccountId,
+            /// The number of proposals the citizen voted for.
+            proposals: u32,
+        },
+        /// A proposal was funded by the close of its round.
+        RoundProposalFunded {
+            /// The ID of the round.
+            round_id: u32,
+            /// The ID of the proposal.
+            proposal_id: u64,
+            /// The score the proposal received.
+            score: u64,
+        },
+        /// A participatory budgeting round was closed.
+        RoundClosed {
+            /// The ID of the round.
+            round_id: u32,
+            /// The number of proposals funded.
+            funded: u32,
+            /// The amount of the pot allocated to funded proposals.
+            spent: T::Balance,
+        },
+        /// A government wallet moved funds into the account it pays proposals from.
+        WalletFunded {
+            /// The government wallet.
+            wallet: T::AccountId,
+            /// The asset moved.
+            asset: T::AssetId,
+            /// The amount moved.
+            amount: T::Balance,
+        },
     }
     /// Storage for budget proposals.
     #[pallet::storage]
@@ -60,13 +530,232 @@ pub mod pallet {
         _,
         Twox64Concat,
         u64,
-        ProposalDetails<T::AccountId>,
+        ProposalDetails<T::AccountId, T::Balance, T::AssetId>,
     >;
 
     /// Storage for the next proposal ID.
     #[pallet::storage]
     pub type NextProposalId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Storage for the edit history of each proposal, oldest revision first.
+    #[pallet::storage]
+    pub type ProposalRevisions<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BoundedVec<ProposalRevision<T::Balance>, T::MaxRevisions>,
+        ValueQuery,
+    >;
+
+    /// Storage for the proposals due to expire at each block.
+    #[pallet::storage]
+    pub type ProposalExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // block number
+        Twox64Concat,
+        u64, // proposal_id
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the earliest block with expiries carried over from an earlier block.
+    #[pallet::storage]
+    pub type ExpiryBacklog<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Storage for the ceiling and committed total of each fiscal period, per asset.
+    #[pallet::storage]
+    pub type FiscalBudgets<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32, // fiscal period
+        Blake2_128Concat,
+        T::AssetId,
+        FiscalBudget<T::Balance>,
+        ValueQuery,
+    >;
+
+    /// Storage for the registered budget categories.
+    #[pallet::storage]
+    pub type BudgetCategories<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32, // category
+        BudgetCategory,
+    >;
+
+    /// Storage for the next budget category ID.
+    #[pallet::storage]
+    pub type NextCategoryId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Storage for the allocation and committed total of each category per fiscal period,
+    /// per asset.
+    #[pallet::storage]
+    pub type CategoryBudgets<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, u32>, // fiscal period
+            NMapKey<Twox64Concat, u32>, // category
+            NMapKey<Blake2_128Concat, T::AssetId>,
+        ),
+        FiscalBudget<T::Balance>,
+        ValueQuery,
+    >;
+
+    /// Storage for the approved recurring budget lines.
+    #[pallet::storage]
+    pub type RecurringLines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32, // line_id
+        RecurringLine<T::AccountId, T::Balance, T::AssetId>,
+    >;
+
+    /// Storage for the next recurring line ID.
+    #[pallet::storage]
+    pub type NextRecurringLineId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Storage for the recurring line payments due at each block.
+    #[pallet::storage]
+    pub type RecurringPayments<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // block number
+        BoundedVec<u32, T::MaxRecurringPaymentsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage for the expense items reported against each proposal.
+    #[pallet::storage]
+    pub type ExpenseReports<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BoundedVec<ExpenseItem<T::Balance>, T::MaxExpenseItems>,
+        ValueQuery,
+    >;
+
+    /// Storage for the reported total and reporting deadline of each proposal.
+    #[pallet::storage]
+    pub type Reconciliations<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        Reconciliation<T::Balance>,
+        ValueQuery,
+    >;
+
+    /// Storage for the proposals whose reporting deadline falls at each block.
+    #[pallet::storage]
+    pub type ReportingDeadlines<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // block number
+        Twox64Concat,
+        u64, // proposal_id
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the earliest block with reporting deadlines carried over from an earlier
+    /// block.
+    #[pallet::storage]
+    pub type ReportingBacklog<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Storage for the clawbacks made against each proposal.
+    #[pallet::storage]
+    pub type Clawbacks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BoundedVec<ClawbackRecord<T::Balance>, T::MaxClawbacks>,
+        ValueQuery,
+    >;
+
+    /// Storage for participatory budgeting rounds.
+    #[pallet::storage]
+    pub type Rounds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32, // round_id
+        Round<T::Balance, T::AssetId>,
+        OptionQuery,
+    >;
+
+    /// Storage for the next round ID.
+    #[pallet::storage]
+    pub type NextRoundId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Storage for the proposals entered into each round.
+    #[pallet::storage]
+    pub type RoundProposals<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32, // round_id
+        BoundedVec<u64, T::MaxRoundProposals>,
+        ValueQuery,
+    >;
+
+    /// Storage for the score of each proposal in a round.
+    #[pallet::storage]
+    pub type RoundScores<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32, // round_id
+        Twox64Concat,
+        u64, // proposal_id
+        u64,
+        ValueQuery,
+    >;
+
+    /// Storage for the ballot of each citizen in a round, as `(proposal_id, points)` pairs.
+    #[pallet::storage]
+    pub type RoundBallots<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32, // round_id
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(u64, u32), T::MaxRoundProposals>,
+        ValueQuery,
+    >;
+
+    /// Storage for the accounts that endorsed each proposal.
+    #[pallet::storage]
+    pub type Endorsements<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BoundedVec<T::AccountId, T::MaxEndorsements>,
+        ValueQuery,
+    >;
+
+    /// Storage for the declared conflicts of interest of each approver, keyed by approver
+    /// and the account they are related to.
+    #[pallet::storage]
+    pub type ConflictDeclarations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // approver
+        Blake2_128Concat,
+        T::AccountId, // related account
+        ConflictDeclaration,
+        OptionQuery,
+    >;
+
+    /// Storage for the approvers who recused themselves from each proposal, and the block at
+    /// which they did.
+    #[pallet::storage]
+    pub type Recusals<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        Blake2_128Concat,
+        T::AccountId, // approver
+        u64,
+        OptionQuery,
+    >;
+
     #[pallet::error]
     pub enum Error<T> {
         /// Proposal does not exist.
@@ -81,57 +770,497 @@ pub mod pallet {
         NotProposalCreator,
         /// Proposal is not in pending state.
         ProposalNotPending,
+        /// Proposal has reached the maximum number of revisions.
+        TooManyRevisions,
+        /// Approving the proposal would exceed the budget ceiling of the fiscal period.
+        BudgetCeilingExceeded,
+        /// Budget category does not exist.
+        CategoryDoesNotExist,
+        /// Invalid budget category name.
+        InvalidCategoryName,
+        /// Approving the proposal would exceed the allocation of its budget category.
+        CategoryAllocationExceeded,
+        /// Asset does not exist.
+        UnknownAsset,
+        /// Government wallet does not exist.
+        UnknownWallet,
+        /// Payment schedule has no installments or a zero interval.
+        InvalidSchedule,
+        /// Proposal is not approved.
+        ProposalNotApproved,
+        /// No payment of the proposal is due yet.
+        PaymentNotDue,
+        /// Proposal is not awaiting execution.
+        ProposalNotAwaitingExecution,
+        /// The challenge period of the proposal has not ended yet.
+        ChallengePeriodActive,
+        /// The challenge period of the proposal has already ended.
+        ChallengePeriodOver,
+        /// Proposal is not under referendum.
+        ProposalNotUnderReferendum,
+        /// Recurring line does not exist.
+        RecurringLineDoesNotExist,
+        /// Recurring line has a zero amount or interval, or ends before its first payment.
+        InvalidRecurringLine,
+        /// Recurring line is not paused.
+        RecurringLineNotPaused,
+        /// Recurring line has already ended or been cancelled.
+        RecurringLineFinished,
+        /// Too many recurring payments are already scheduled in the same block.
+        RecurringQueueFull,
+        /// Only the beneficiary can report expenses.
+        NotProposalBeneficiary,
+        /// Proposal has not been paid out.
+        ProposalNotDisbursed,
+        /// Expense report has no items, or an item has a zero amount or invalid description.
+        InvalidExpenseReport,
+        /// Proposal has reached the maximum number of expense items.
+        TooManyExpenseItems,
+        /// Reported expenses would exceed the amount disbursed.
+        ExpensesExceedDisbursed,
+        /// Proposal has not been paid out, or was already clawed back.
+        ProposalNotClawable,
+        /// Clawback amount is zero or exceeds the unspent balance of the proposal.
+        InvalidClawbackAmount,
+        /// Proposal has reached the maximum number of clawbacks.
+        TooManyClawbacks,
+        /// Round does not exist.
+        RoundDoesNotExist,
+        /// Round pot or duration is invalid.
+        InvalidRound,
+        /// Round is closed to new entries and votes.
+        RoundNotOpen,
+        /// Round voting has not ended yet.
+        RoundStillOpen,
+        /// Round has already been closed.
+        RoundAlreadyClosed,
+        /// Round has reached the maximum number of proposals.
+        TooManyRoundProposals,
+        /// Proposal is not pending, or does not fit the asset and pot of the round.
+        ProposalNotEligibleForRound,
+        /// Account is not an eligible citizen.
+        NotEligibleCitizen,
+        /// Ballot is empty, repeats a proposal, names a proposal outside the round or
+        /// exceeds the points of the round.
+        InvalidBallot,
+        /// Proposal is not seeking endorsements.
+        ProposalNotSeekingEndorsements,
+        /// Creators cannot endorse their own proposals.
+        CannotEndorseOwnProposal,
+        /// Account has already endorsed the proposal.
+        AlreadyEndorsed,
+        /// Proposal has reached the maximum number of endorsements.
+        TooManyEndorsements,
+        /// Approver has declared a conflict of interest with the creator or beneficiary.
+        ConflictOfInterest,
+        /// Approver has recused themselves from the proposal.
+        RecusedFromProposal,
+        /// Approver has already recused themselves from the proposal.
+        AlreadyRecused,
+        /// Conflict of interest has already been declared.
+        ConflictAlreadyDeclared,
+        /// Conflict of interest has not been declared.
+        ConflictDoesNotExist,
+        /// An account cannot declare a conflict of interest with itself.
+        InvalidConflict,
     }
 
     /// Status of a budget proposal
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
     pub enum ProposalStatus {
+        /// Proposal is gathering endorsements before it is reviewed
+        SeekingEndorsements,
         /// Proposal is pending approval
         Pending,
-        /// Proposal has been approved
+        /// Proposal is being decided by a community referendum
+        UnderReferendum,
+        /// Proposal has been approved and is waiting out its challenge period
+        AwaitingExecution,
+        /// Proposal cleared its challenge period and is being paid out
         Approved,
         /// Proposal has been rejected
         Rejected,
+        /// Proposal was withdrawn by its creator
+        Withdrawn,
+        /// Proposal passed its lifetime without a decision
+        Expired,
+        /// Proposal has been paid out in full
+        Executed,
+        /// Proposal was vetoed during its challenge period
+        Vetoed,
+        /// Unspent or misused funds of the proposal were recovered into the treasury
+        ClawedBack,
+        /// Proposal is competing for funding in a participatory budgeting round
+        InRound,
+    }
+
+    /// When the approved amount of a proposal is paid to its beneficiary
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum PaymentSchedule {
+        /// The full amount is paid once the proposal becomes executable
+        LumpSum,
+        /// The amount is split into equal installments, the first due once the proposal
+        /// becomes executable and each
+        /// following one `interval` blocks after the previous
+        Periodic { installments: u32, interval: u64 },
+        /// The full amount is paid once the given block is reached
+        FromBlock(u64),
     }
 
     /// Proposal details structure
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub struct ProposalDetails<AccountId> {
+    pub struct ProposalDetails<AccountId, Balance, AssetId> {
         /// The creator of the proposal
         pub creator: AccountId,
+        /// The account that receives the funds
+        pub beneficiary: AccountId,
+        /// The government wallet paying the proposal, or the treasury when not set
+        pub source: Option<AccountId>,
+        /// When the approved amount is paid
+        pub schedule: PaymentSchedule,
         /// The amount requested
-        pub amount: u128,
+        pub amount: Balance,
+        /// The asset the amount is requested in
+        pub asset: AssetId,
         /// The purpose of the proposal
         pub purpose: BoundedVec<u8, ConstU32<200>>,
+        /// The budget category the proposal draws on
+        pub category: u32,
         /// Current status of the proposal
         pub status: ProposalStatus,
         /// When the proposal was created
         pub created_at: u64,
         /// When the proposal was last updated
         pub updated_at: u64,
+        /// Fiscal period the approved amount is committed against
+        pub fiscal_period: Option<u32>,
+        /// When the proposal was approved
+        pub approved_at: Option<u64>,
+        /// When the challenge period of the approved proposal ends
+        pub executable_at: Option<u64>,
+        /// Amount paid to the beneficiary so far
+        pub disbursed: Balance,
+        /// Number of installments paid so far
+        pub installments_paid: u32,
     }
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Create a new budget proposal.
-        ///
-        /// The dispatch origin must be signed.
-        ///
-        /// - `amount`: The amount requested.
-        /// - `purpose`: The purpose of the proposal.
-        ///
-        /// Emits `BudgetProposalCreated` event when successful.
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::create_proposal())] 
-        pub fn create_proposal(
-            origin: OriginFor<T>,
-            amount: u128,
-            purpose: Vec<u8>,
-        ) -> DispatchResult {
-            let creator = ensure_signed(origin)?;
 
-            // Check if purpose is valid
-            ensure!(!purpose.is_empty(), Error::<T>::InvalidPurpose);
-            ensure!(purpose.len() <= 200, Error::<T>::InvalidPurpose);
+    /// Budget of a single fiscal period
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct FiscalBudget<Balance> {
+        /// Total amount that may be approved in the period, unlimited when not set
+        pub ceiling: Option<Balance>,
+        /// Total amount already approved in the period
+        pub committed: Balance,
+    }
+
+    impl<Balance: AtLeast32BitUnsigned + Copy> FiscalBudget<Balance> {
+        /// Add `amount` to the committed total, returning `None` if the ceiling would be exceeded.
+        pub fn commit(&mut self, amount: Balance) -> Option<()> {
+            let committed = self.committed.checked_add(&amount)?;
+            if let Some(ceiling) = self.ceiling {
+                if committed > ceiling {
+                    return None;
+                }
+            }
+            self.committed = committed;
+            Some(())
+        }
+
+        /// Remove `amount` from the committed total.
+        pub fn release(&mut self, amount: Balance) {
+            self.committed = self.committed.saturating_sub(amount);
+        }
+    }
+
+    /// A budget category, such as a department or spending line
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct BudgetCategory {
+        /// The name of the category
+        pub name: BoundedVec<u8, ConstU32<50>>,
+    }
+
+    /// Status of a recurring budget line
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RecurringLineStatus {
+        /// Payments are made every interval
+        Active,
+        /// Payments stopped after a failure until the line is resumed
+        Paused,
+        /// The last payment before the end block was made
+        Ended,
+        /// The line was cancelled by the budget origin
+        Cancelled,
+    }
+
+    /// Reason a recurring payment could not be made
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RecurringPaymentFailure {
+        /// The treasury does not hold enough of the asset
+        InsufficientFunds,
+        /// The payment would exceed the fiscal period ceiling or category allocation
+        BudgetExceeded,
+        /// The transfer to the beneficiary failed
+        TransferFailed,
+        /// The block of the next payment has no room left in the schedule
+        QueueFull,
+    }
+
+    /// A budget line paid automatically from the treasury every interval
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct RecurringLine<AccountId, Balance, AssetId> {
+        /// The account that receives the payments
+        pub beneficiary: AccountId,
+        /// The asset the payments are made in
+        pub asset: AssetId,
+        /// The amount paid every interval
+        pub amount: Balance,
+        /// The budget category the payments draw on
+        pub category: u32,
+        /// The number of blocks between payments
+        pub interval: u64,
+        /// The block of the next payment
+        pub next_payment: u64,
+        /// No payment is made after this block
+        pub end: u64,
+        /// Current status of the line
+        pub status: RecurringLineStatus,
+        /// Number of payments made so far
+        pub payments_made: u32,
+        /// The block and reason of the most recent failed payment
+        pub last_failure: Option<(u64, RecurringPaymentFailure)>,
+    }
+
+    /// An expense paid from the funds of a proposal
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ExpenseItem<Balance> {
+        /// The amount spent
+        pub amount: Balance,
+        /// Hash of the receipt, kept off-chain
+        pub receipt_hash: [u8; 32],
+        /// What the amount was spent on
+        pub description: BoundedVec<u8, ConstU32<100>>,
+    }
+
+    /// Criteria for listing proposals; fields left unset match every proposal
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct ProposalFilter<AccountId, Balance> {
+        /// Only proposals with this status
+        pub status: Option<ProposalStatus>,
+        /// Only proposals created by this account
+        pub creator: Option<AccountId>,
+        /// Only proposals in this budget category
+        pub category: Option<u32>,
+        /// Only proposals requesting at least this amount
+        pub min_amount: Option<Balance>,
+        /// Only proposals requesting at most this amount
+        pub max_amount: Option<Balance>,
+    }
+
+    impl<AccountId: PartialEq, Balance: PartialOrd> ProposalFilter<AccountId, Balance> {
+        /// Whether `proposal` meets every criterion of the filter.
+        pub fn matches<AssetId>(
+            &self,
+            proposal: &ProposalDetails<AccountId, Balance, AssetId>,
+        ) -> bool {
+            self.status.as_ref().map_or(true, |status| proposal.status == *status) &&
+                self.creator.as_ref().map_or(true, |creator| proposal.creator == *creator) &&
+                self.category.map_or(true, |category| proposal.category == category) &&
+                self.min_amount.as_ref().map_or(true, |min| proposal.amount >= *min) &&
+                self.max_amount.as_ref().map_or(true, |max| proposal.amount <= *max)
+        }
+    }
+
+    /// Amounts requested by proposals in one asset, grouped by outcome
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+    pub struct ProposalTotals<Balance> {
+        /// Proposals still awaiting a decision
+        pub pending: Balance,
+        /// Proposals approved, whether or not they have been paid out or clawed back
+        pub approved: Balance,
+        /// Proposals rejected or vetoed
+        pub rejected: Balance,
+    }
+
+    /// Expense reporting state of a proposal
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct Reconciliation<Balance> {
+        /// Total amount covered by expense reports
+        pub reported: Balance,
+        /// Total amount recovered into the treasury by clawbacks
+        pub clawed_back: Balance,
+        /// Block by which the disbursed amount must be fully reported, set on execution
+        pub deadline: Option<u64>,
+        /// Whether the deadline passed with part of the disbursed amount unreported
+        pub flagged: bool,
+    }
+
+    /// Funds recovered from a proposal by the enforcement origin
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ClawbackRecord<Balance> {
+        /// The amount recovered
+        pub amount: Balance,
+        /// The reason for the clawback
+        pub reason: BoundedVec<u8, ConstU32<100>>,
+        /// The block at which the funds were recovered
+        pub at: u64,
+    }
+
+    /// How an approver is related to an account whose proposals they must not approve
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Relationship {
+        /// A relative of the approver
+        Family,
+        /// A business partner or company the approver has an interest in
+        Business,
+        /// The approver's employer or employee
+        Employment,
+        /// Any other relationship that could bias the approver
+        Other,
+    }
+
+    /// Who declared a conflict of interest
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum DeclarationSource {
+        /// The approver declared it themselves
+        Approver,
+        /// The ethics origin declared it
+        Ethics,
+    }
+
+    /// A declared conflict of interest of an approver
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ConflictDeclaration {
+        /// How the approver is related to the account
+        pub relationship: Relationship,
+        /// Who made the declaration
+        pub declared_by: DeclarationSource,
+        /// The block at which the declaration was made
+        pub declared_at: u64,
+    }
+
+    /// How citizens vote in a participatory budgeting round
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RoundVoting {
+        /// Each citizen splits this many points across the proposals
+        Points(u32),
+        /// Each citizen approves any number of proposals, one point each
+        Approval,
+    }
+
+    /// A participatory budgeting round splitting a fixed pot across proposals
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Round<Balance, AssetId> {
+        /// The amount the round can fund
+        pub pot: Balance,
+        /// The asset the pot is held in
+        pub asset: AssetId,
+        /// How citizens vote
+        pub voting: RoundVoting,
+        /// The block at which the round was opened
+        pub opened_at: u64,
+        /// The block at which voting closes
+        pub closes_at: u64,
+        /// Whether the round has been closed and its proposals decided
+        pub closed: bool,
+        /// The amount of the pot allocated to funded proposals
+        pub spent: Balance,
+    }
+
+    /// A previous version of a proposal, kept when the creator edits it
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ProposalRevision<Balance> {
+        /// The amount requested before the edit
+        pub amount: Balance,
+        /// The purpose before the edit
+        pub purpose: BoundedVec<u8, ConstU32<200>>,
+        /// When the edit was made
+        pub revised_at: u64,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let block_number: u64 = match n.try_into() {
+                Ok(block_number) => block_number,
+                Err(_) => return Weight::zero(),
+            };
+
+            // Expire the undecided proposals scheduled up to this block, carrying over the rest
+            let (count, visited) = Self::process_expiries(block_number);
+
+            // Pay every recurring line due in this block
+            let due = RecurringPayments::<T>::take(block_number);
+            let payments = due.len() as u32;
+            for line_id in due {
+                Self::pay_recurring_line(line_id, block_number);
+            }
+
+            // Flag the proposals reaching their reporting deadline with expenses unreported,
+            // carrying over the rest
+            let (checked, deadline_blocks) = Self::process_reporting_deadlines(block_number);
+
+            T::WeightInfo::expire_proposals(count)
+                .saturating_add(T::DbWeight::get().reads(visited.into()))
+                .saturating_add(T::WeightInfo::pay_recurring_lines(payments))
+                .saturating_add(T::WeightInfo::check_reporting_deadlines(checked))
+                .saturating_add(T::DbWeight::get().reads(deadline_blocks.into()))
+        }
+    }
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new budget proposal.
+        ///
+        /// The dispatch origin must be signed.
+        ///
+        /// - `amount`: The amount requested.
+        /// - `purpose`: The purpose of the proposal.
+        /// - `category`: The budget category the proposal draws on.
+        /// - `asset`: The asset the amount is requested in.
+        /// - `beneficiary`: The account that receives the funds.
+        /// - `source`: The government wallet to pay from, or `None` to pay from the treasury.
+        /// - `schedule`: When the approved amount is paid.
+        ///
+        /// Emits `BudgetProposalCreated` event when successful.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_proposal())] 
+        #[allow(clippy::too_many_arguments)]
+        pub fn create_proposal(
+            origin: OriginFor<T>,
+            amount: T::Balance,
+            purpose: Vec<u8>,
+            category: u32,
+            asset: T::AssetId,
+            beneficiary: T::AccountId,
+            source: Option<T::AccountId>,
+            schedule: PaymentSchedule,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+
+            // Check if purpose is valid
+            ensure!(!purpose.is_empty(), Error::<T>::InvalidPurpose);
+            ensure!(purpose.len() <= 200, Error::<T>::InvalidPurpose);
+
+            // Check if category exists
+            ensure!(
+                BudgetCategories::<T>::contains_key(category),
+                Error::<T>::CategoryDoesNotExist
+            );
+
+            // Check if asset exists
+            ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+            // Check if the paying wallet exists
+            if let Some(wallet) = &source {
+                ensure!(T::Wallets::wallet_exists(wallet), Error::<T>::UnknownWallet);
+            }
+
+            // Check if payment schedule is valid
+            if let PaymentSchedule::Periodic { installments, interval } = schedule {
+                ensure!(installments > 0 && interval > 0, Error::<T>::InvalidSchedule);
+            }
 
             // Convert purpose to bounded vec
             let bounded_purpose = BoundedVec::try_from(purpose.clone())
@@ -146,32 +1275,64 @@ pub mod pallet {
             let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
 
             // Create proposal details
-            let proposal_details = ProposalDetails {
+            let mut proposal_details = ProposalDetails {
                 creator: creator.clone(),
+                beneficiary: beneficiary.clone(),
+                source,
+                schedule,
                 amount,
+                asset: asset.clone(),
                 purpose: bounded_purpose.clone(), // FIXED: Clone here
+                category,
                 status: ProposalStatus::Pending,
                 created_at: block_number,
                 updated_at: block_number,
+                fiscal_period: None,
+                approved_at: None,
+                executable_at: None,
+                disbursed: Zero::zero(),
+                installments_paid: 0,
             };
 
-            // Store proposal
+            // Proposals needing endorsements wait for them, large proposals go to a
+            // referendum, and the rest expire if left undecided
+            let seeking_endorsements = Self::required_endorsements(amount) > 0;
+            let referendum = !seeking_endorsements && amount > T::ReferendumThreshold::get();
+            if seeking_endorsements {
+                proposal_details.status = ProposalStatus::SeekingEndorsements;
+                Self::schedule_expiry(proposal_id, block_number);
+            } else if referendum {
+                proposal_details.status = ProposalStatus::UnderReferendum;
+            } else {
+                Self::schedule_expiry(proposal_id, block_number);
+            }
+
+            // Store proposal before opening its referendum, so the provider can read it
             BudgetProposals::<T>::insert(proposal_id, proposal_details);
+            if referendum {
+                T::Referenda::open_referendum(proposal_id)?;
+            }
 
             // Emit event
             Self::deposit_event(Event::<T>::BudgetProposalCreated {
                 proposal_id,
                 creator: creator.clone(),
+                beneficiary,
                 amount,
                 purpose: bounded_purpose, // Original still available
+                category,
+                asset,
             });
+            if referendum {
+                Self::deposit_event(Event::<T>::BudgetProposalReferendumOpened { proposal_id });
+            }
 
             Ok(())
         }
 
         /// Approve a budget proposal.
         ///
-        /// The dispatch origin must be signed.
+        /// The dispatch origin must be `ApproverOrigin`.
         ///
         /// - `proposal_id`: The ID of the proposal to approve.
         ///
@@ -182,7 +1343,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             proposal_id: u64,
         ) -> DispatchResult {
-            let approver = ensure_signed(origin)?;
+            let approver = T::ApproverOrigin::ensure_origin(origin)?;
 
             // Get proposal
             let mut proposal = BudgetProposals::<T>::get(proposal_id)
@@ -194,13 +1355,15 @@ pub mod pallet {
                 Error::<T>::ProposalNotPending
             );
 
-            // Update proposal status
-            proposal.status = ProposalStatus::Approved;
-            
+            // Check if the approver is free to decide the proposal
+            Self::ensure_can_decide(&approver, proposal_id, &proposal)?;
+
             // Get current block number for update timestamp
             let current_block = frame_system::Pallet::<T>::block_number();
             let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
-            proposal.updated_at = block_number;
+
+            // Commit the budget and start the challenge period
+            Self::do_approve(&mut proposal, block_number)?;
 
             // Store updated proposal
             BudgetProposals::<T>::insert(proposal_id, proposal);
@@ -216,7 +1379,7 @@ pub mod pallet {
 
         /// Reject a budget proposal.
         ///
-        /// The dispatch origin must be signed.
+        /// The dispatch origin must be `ApproverOrigin`.
         ///
         /// - `proposal_id`: The ID of the proposal to reject.
         /// - `reason`: The reason for rejection.
@@ -229,7 +1392,7 @@ pub mod pallet {
             proposal_id: u64,
             reason: Vec<u8>,
         ) -> DispatchResult {
-            let rejecter = ensure_signed(origin)?;
+            let rejecter = T::ApproverOrigin::ensure_origin(origin)?;
 
             // Check if reason is valid
             ensure!(!reason.is_empty(), Error::<T>::InvalidReason);
@@ -249,6 +1412,9 @@ pub mod pallet {
                 Error::<T>::ProposalNotPending
             );
 
+            // Check if the rejecter is free to decide the proposal
+            Self::ensure_can_decide(&rejecter, proposal_id, &proposal)?;
+
             // Update proposal status
             proposal.status = ProposalStatus::Rejected;
             
@@ -269,6 +1435,1687 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Update the amount and purpose of a pending budget proposal.
+        ///
+        /// The dispatch origin must be signed by the proposal creator. The previous
+        /// amount and purpose are kept in `ProposalRevisions`. A proposal raised beyond its
+        /// endorsements goes back to seeking endorsements, and one raised above
+        /// `ReferendumThreshold` is put to a referendum instead of waiting for an approver.
+        ///
+        /// - `proposal_id`: The ID of the proposal to update.
+        /// - `amount`: The new amount requested.
+        /// - `purpose`: The new purpose of the proposal.
+        ///
+        /// Emits `BudgetProposalUpdated` event when successful.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::update_proposal())]
+        pub fn update_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            amount: T::Balance,
+            purpose: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Check if purpose is valid
+            ensure!(!purpose.is_empty(), Error::<T>::InvalidPurpose);
+            let bounded_purpose: BoundedVec<u8, ConstU32<200>> = BoundedVec::try_from(purpose)
+                .map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+            // Only the creator may edit, and only before any approval activity
+            ensure!(proposal.creator == who, Error::<T>::NotProposalCreator);
+            ensure!(
+                proposal.status == ProposalStatus::Pending,
+                Error::<T>::ProposalNotPending
+            );
+
+            // Get current block number for update timestamp
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            // Keep the previous version in the revision history
+            let revision = ProposalRevisions::<T>::try_mutate(proposal_id, |revisions| {
+                revisions
+                    .try_push(ProposalRevision {
+                        amount: proposal.amount,
+                        purpose: proposal.purpose.clone(),
+                        revised_at: block_number,
+                    })
+                    .map_err(|_| Error::<T>::TooManyRevisions)?;
+                Ok::<u32, Error<T>>(revisions.len() as u32)
+            })?;
+
+            // Larger amounts need more endorsements and large ones a referendum, as on creation
+            let endorsements = Endorsements::<T>::decode_len(proposal_id).unwrap_or(0) as u32;
+            let seeking_endorsements = Self::required_endorsements(amount) > endorsements;
+            let referendum = !seeking_endorsements && amount > T::ReferendumThreshold::get();
+            if seeking_endorsements {
+                proposal.status = ProposalStatus::SeekingEndorsements;
+            } else if referendum {
+                proposal.status = ProposalStatus::UnderReferendum;
+            }
+
+            // Update proposal before opening its referendum, so the provider can read it
+            proposal.amount = amount;
+            proposal.purpose = bounded_purpose.clone();
+            proposal.updated_at = block_number;
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+            if referendum {
+                T::Referenda::open_referendum(proposal_id)?;
+            }
+
+            // Emit events
+            Self::deposit_event(Event::<T>::BudgetProposalUpdated {
+                proposal_id,
+                amount,
+                purpose: bounded_purpose,
+                revision,
+            });
+            if referendum {
+                Self::deposit_event(Event::<T>::BudgetProposalReferendumOpened { proposal_id });
+            }
+
+            Ok(())
+        }
+
+        /// Withdraw a pending budget proposal, or one still seeking endorsements.
+        ///
+        /// The dispatch origin must be signed by the proposal creator.
+        ///
+        /// - `proposal_id`: The ID of the proposal to withdraw.
+        ///
+        /// Emits `BudgetProposalWithdrawn` event when successful.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::withdraw_proposal())]
+        pub fn withdraw_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+            // Only the creator may withdraw, and only before review
+            ensure!(proposal.creator == who, Error::<T>::NotProposalCreator);
+            ensure!(
+                matches!(
+                    proposal.status,
+                    ProposalStatus::Pending | ProposalStatus::SeekingEndorsements
+                ),
+                Error::<T>::ProposalNotPending
+            );
+
+            // Update proposal status
+            proposal.status = ProposalStatus::Withdrawn;
+
+            // Get current block number for update timestamp
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            proposal.updated_at = block_number;
+
+            // Store updated proposal
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::BudgetProposalWithdrawn {
+                proposal_id,
+                creator: who,
+            });
+
+            Ok(())
+        }
+
+        /// Set the budget ceiling of a fiscal period.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`.
+        ///
+        /// - `period`: The fiscal period to set the ceiling for.
+        /// - `asset`: The asset the ceiling applies to.
+        /// - `ceiling`: The total amount that may be approved in the period.
+        ///
+        /// Emits `BudgetCeilingSet` event when successful.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_budget_ceiling())]
+        pub fn set_budget_ceiling(
+            origin: OriginFor<T>,
+            period: u32,
+            asset: T::AssetId,
+            ceiling: T::Balance,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+            FiscalBudgets::<T>::mutate(period, &asset, |budget| budget.ceiling = Some(ceiling));
+
+            Self::deposit_event(Event::<T>::BudgetCeilingSet { period, asset, ceiling });
+
+            Ok(())
+        }
+
+        /// Register a new budget category.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`.
+        ///
+        /// - `name`: The name of the category, such as a department.
+        ///
+        /// Emits `BudgetCategoryRegistered` event when successful.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::register_category())]
+        pub fn register_category(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            // Check if name is valid
+            ensure!(!name.is_empty(), Error::<T>::InvalidCategoryName);
+            let bounded_name: BoundedVec<u8, ConstU32<50>> = BoundedVec::try_from(name)
+                .map_err(|_| Error::<T>::InvalidCategoryName)?;
+
+            // Get next category ID
+            let category = NextCategoryId::<T>::get();
+            NextCategoryId::<T>::put(category.saturating_add(1));
+
+            BudgetCategories::<T>::insert(category, BudgetCategory { name: bounded_name.clone() });
+
+            Self::deposit_event(Event::<T>::BudgetCategoryRegistered {
+                category,
+                name: bounded_name,
+            });
+
+            Ok(())
+        }
+
+        /// Set the allocation of a budget category for a fiscal period.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`.
+        ///
+        /// - `period`: The fiscal period to set the allocation for.
+        /// - `category`: The budget category.
+        /// - `asset`: The asset the allocation applies to.
+        /// - `allocation`: The total amount that may be approved for the category in the period.
+        ///
+        /// Emits `CategoryAllocationSet` event when successful.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_category_allocation())]
+        pub fn set_category_allocation(
+            origin: OriginFor<T>,
+            period: u32,
+            category: u32,
+            asset: T::AssetId,
+            allocation: T::Balance,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                BudgetCategories::<T>::contains_key(category),
+                Error::<T>::CategoryDoesNotExist
+            );
+            ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+            CategoryBudgets::<T>::mutate((period, category, &asset), |budget| {
+                budget.ceiling = Some(allocation)
+            });
+
+            Self::deposit_event(Event::<T>::CategoryAllocationSet {
+                period,
+                category,
+                asset,
+                allocation,
+            });
+
+            Ok(())
+        }
+
+        /// Pay the beneficiary of an approved proposal whatever its schedule has made due.
+        ///
+        /// The dispatch origin must be signed. The proposal's challenge period must have
+        /// ended. Payments come from the pallet's account for the proposal's government wallet
+        /// and are debited from its ledger, or come from the treasury when it names none.
+        ///
+        /// - `proposal_id`: The ID of the proposal to execute.
+        ///
+        /// Emits `ProposalPaymentExecuted` event when successful, and `BudgetProposalExecuted`
+        /// once the full amount has been paid.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::execute_proposal())]
+        pub fn execute_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+            // Check if proposal is approved
+            ensure!(
+                matches!(
+                    proposal.status,
+                    ProposalStatus::AwaitingExecution | ProposalStatus::Approved
+                ),
+                Error::<T>::ProposalNotApproved
+            );
+
+            // Get current block number
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            // Check if the challenge period has ended
+            ensure!(
+                proposal.executable_at.map_or(false, |at| block_number >= at),
+                Error::<T>::ChallengePeriodActive
+            );
+
+            // Work out how much the schedule has made due
+            let (installments_due, payment) = Self::payment_due(&proposal, block_number);
+            ensure!(!payment.is_zero(), Error::<T>::PaymentNotDue);
+
+            // Transfer from the paying wallet's account or the treasury
+            let source = match &proposal.source {
+                Some(wallet) => {
+                    T::Wallets::debit(wallet, payment)?;
+                    Self::wallet_account(wallet)
+                },
+                None => Self::treasury_account(),
+            };
+            T::Assets::transfer(
+                proposal.asset.clone(),
+                &source,
+                &proposal.beneficiary,
+                payment,
+                Preservation::Expendable,
+            )?;
+
+            // Update proposal
+            proposal.disbursed = proposal.disbursed.saturating_add(payment);
+            proposal.installments_paid = installments_due;
+            proposal.updated_at = block_number;
+            let completed = proposal.disbursed >= proposal.amount;
+            proposal.status = if completed {
+                ProposalStatus::Executed
+            } else {
+                ProposalStatus::Approved
+            };
+
+            // Give the beneficiary until the reporting deadline to account for the funds
+            if completed {
+                let deadline = Self::schedule_reporting_deadline(proposal_id, block_number);
+                Reconciliations::<T>::mutate(proposal_id, |reconciliation| {
+                    reconciliation.deadline = Some(deadline)
+                });
+            }
+            let beneficiary = proposal.beneficiary.clone();
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+
+            // Emit events
+            Self::deposit_event(Event::<T>::ProposalPaymentExecuted {
+                proposal_id,
+                source,
+                beneficiary,
+                amount: payment,
+            });
+            if completed {
+                Self::deposit_event(Event::<T>::BudgetProposalExecuted { proposal_id });
+            }
+
+            Ok(())
+        }
+
+        /// Veto an approved proposal during its challenge period.
+        ///
+        /// The dispatch origin must be `OversightOrigin`. The amount committed on approval is
+        /// released back to the fiscal period and category budgets.
+        ///
+        /// - `proposal_id`: The ID of the proposal to veto.
+        /// - `reason`: The reason for the veto.
+        ///
+        /// Emits `BudgetProposalVetoed` event when successful.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::veto_proposal())]
+        pub fn veto_proposal(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::OversightOrigin::ensure_origin(origin)?;
+
+            // Check if reason is valid
+            ensure!(!reason.is_empty(), Error::<T>::InvalidReason);
+            let bounded_reason: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(reason)
+                .map_err(|_| Error::<T>::InvalidReason)?;
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+            // Check if proposal is still in its challenge period
+            ensure!(
+                proposal.status == ProposalStatus::AwaitingExecution,
+                Error::<T>::ProposalNotAwaitingExecution
+            );
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            ensure!(
+                proposal.executable_at.map_or(true, |at| block_number < at),
+                Error::<T>::ChallengePeriodOver
+            );
+
+            // Release the committed amount
+            if let Some(period) = proposal.fiscal_period {
+                Self::release_budget(period, proposal.category, &proposal.asset, proposal.amount);
+            }
+
+            // Update proposal status
+            proposal.status = ProposalStatus::Vetoed;
+            proposal.updated_at = block_number;
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::BudgetProposalVetoed {
+                proposal_id,
+                reason: bounded_reason,
+            });
+
+            Ok(())
+        }
+
+        /// Approve a recurring budget line paid from the treasury every `interval` blocks.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`.
+        ///
+        /// - `beneficiary`: The account that receives the payments.
+        /// - `asset`: The asset the payments are made in.
+        /// - `amount`: The amount paid every interval.
+        /// - `category`: The budget category the payments draw on.
+        /// - `interval`: The number of blocks between payments.
+        /// - `first_payment`: The block of the first payment, after the current block.
+        /// - `end`: No payment is made after this block.
+        ///
+        /// Emits `RecurringLineCreated` event when successful.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::create_recurring_line())]
+        #[allow(clippy::too_many_arguments)]
+        pub fn create_recurring_line(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            asset: T::AssetId,
+            amount: T::Balance,
+            category: u32,
+            interval: u64,
+            first_payment: u64,
+            end: u64,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            // Get current block number
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            // Check if line is valid
+            ensure!(
+                !amount.is_zero() && interval > 0 && first_payment > block_number && end >= first_payment,
+                Error::<T>::InvalidRecurringLine
+            );
+            ensure!(
+                BudgetCategories::<T>::contains_key(category),
+                Error::<T>::CategoryDoesNotExist
+            );
+            ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+            // Get next line ID
+            let line_id = NextRecurringLineId::<T>::get();
+            NextRecurringLineId::<T>::put(line_id.saturating_add(1));
+
+            // Schedule the first payment
+            Self::schedule_recurring_payment(line_id, first_payment)?;
+
+            RecurringLines::<T>::insert(
+                line_id,
+                RecurringLine {
+                    beneficiary: beneficiary.clone(),
+                    asset,
+                    amount,
+                    category,
+                    interval,
+                    next_payment: first_payment,
+                    end,
+                    status: RecurringLineStatus::Active,
+                    payments_made: 0,
+                    last_failure: None,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::RecurringLineCreated {
+                line_id,
+                beneficiary,
+                amount,
+                interval,
+            });
+
+            Ok(())
+        }
+
+        /// Resume a recurring budget line paused after a failed payment.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`. Missed payments are not made up; the
+        /// next payment is made in the following block, or at its original block if later.
+        ///
+        /// - `line_id`: The ID of the recurring line to resume.
+        ///
+        /// Emits `RecurringLineResumed` event when successful.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::resume_recurring_line())]
+        pub fn resume_recurring_line(
+            origin: OriginFor<T>,
+            line_id: u32,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            let mut line = RecurringLines::<T>::get(line_id)
+                .ok_or(Error::<T>::RecurringLineDoesNotExist)?;
+            ensure!(
+                line.status == RecurringLineStatus::Paused,
+                Error::<T>::RecurringLineNotPaused
+            );
+
+            // Get current block number
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            // Schedule the next payment
+            let next_payment = line.next_payment.max(block_number.saturating_add(1));
+            ensure!(next_payment <= line.end, Error::<T>::RecurringLineFinished);
+            Self::schedule_recurring_payment(line_id, next_payment)?;
+
+            line.status = RecurringLineStatus::Active;
+            line.next_payment = next_payment;
+            RecurringLines::<T>::insert(line_id, line);
+
+            Self::deposit_event(Event::<T>::RecurringLineResumed { line_id, next_payment });
+
+            Ok(())
+        }
+
+        /// Cancel a recurring budget line so no further payments are made.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`.
+        ///
+        /// - `line_id`: The ID of the recurring line to cancel.
+        ///
+        /// Emits `RecurringLineCancelled` event when successful.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::cancel_recurring_line())]
+        pub fn cancel_recurring_line(
+            origin: OriginFor<T>,
+            line_id: u32,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            let mut line = RecurringLines::<T>::get(line_id)
+                .ok_or(Error::<T>::RecurringLineDoesNotExist)?;
+            ensure!(
+                matches!(line.status, RecurringLineStatus::Active | RecurringLineStatus::Paused),
+                Error::<T>::RecurringLineFinished
+            );
+
+            // A payment still in the schedule is skipped once the line is cancelled
+            line.status = RecurringLineStatus::Cancelled;
+            RecurringLines::<T>::insert(line_id, line);
+
+            Self::deposit_event(Event::<T>::RecurringLineCancelled { line_id });
+
+            Ok(())
+        }
+
+        /// Report what the funds of a proposal were spent on.
+        ///
+        /// The dispatch origin must be signed by the proposal beneficiary, and the proposal
+        /// must have received at least one payment. Reports may be submitted in several
+        /// parts; their total cannot exceed the amount disbursed.
+        ///
+        /// - `proposal_id`: The ID of the proposal the expenses were paid from.
+        /// - `items`: The expenses, each with an amount, receipt hash and description.
+        ///
+        /// Emits `ExpenseReportSubmitted` event when successful, and `ProposalReconciled`
+        /// once the expenses of an executed proposal match the amount disbursed.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::submit_expense_report(items.len() as u32))]
+        pub fn submit_expense_report(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            items: Vec<ExpenseItem<T::Balance>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get proposal
+            let proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(proposal.beneficiary == who, Error::<T>::NotProposalBeneficiary);
+            ensure!(!proposal.disbursed.is_zero(), Error::<T>::ProposalNotDisbursed);
+
+            // Check if report is valid
+            ensure!(!items.is_empty(), Error::<T>::InvalidExpenseReport);
+            ensure!(
+                items.iter().all(|item| !item.amount.is_zero() && !item.description.is_empty()),
+                Error::<T>::InvalidExpenseReport
+            );
+            let item_count = items.len() as u32;
+            let amount = items
+                .iter()
+                .try_fold(T::Balance::zero(), |total, item| total.checked_add(&item.amount))
+                .ok_or(Error::<T>::ExpensesExceedDisbursed)?;
+
+            // Store the items
+            ExpenseReports::<T>::try_mutate(proposal_id, |report| {
+                for item in items {
+                    report.try_push(item).map_err(|_| Error::<T>::TooManyExpenseItems)?;
+                }
+                Ok::<(), Error<T>>(())
+            })?;
+
+            // Update the reported total
+            let mut reconciliation = Reconciliations::<T>::get(proposal_id);
+            let spendable = proposal.disbursed.saturating_sub(reconciliation.clawed_back);
+            let reported = reconciliation
+                .reported
+                .checked_add(&amount)
+                .filter(|reported| *reported <= spendable)
+                .ok_or(Error::<T>::ExpensesExceedDisbursed)?;
+            reconciliation.reported = reported;
+            let reconciled = matches!(
+                proposal.status,
+                ProposalStatus::Executed | ProposalStatus::ClawedBack
+            ) && reported == spendable;
+            if reconciled {
+                reconciliation.flagged = false;
+            }
+            Reconciliations::<T>::insert(proposal_id, reconciliation);
+
+            // Emit events
+            Self::deposit_event(Event::<T>::ExpenseReportSubmitted {
+                proposal_id,
+                items: item_count,
+                amount,
+                reported,
+            });
+            if reconciled {
+                Self::deposit_event(Event::<T>::ProposalReconciled { proposal_id });
+            }
+
+            Ok(())
+        }
+
+        /// Claw back unspent or misused funds of a paid proposal into the treasury.
+        ///
+        /// The dispatch origin must be `EnforcementOrigin`. The funds are recovered from the
+        /// beneficiary, up to the amount disbursed that is not covered by expense reports.
+        /// A proposal that is still being paid out receives no further payments, and the
+        /// recovered and undisbursed amounts are released from its fiscal period and
+        /// category budgets. Several partial clawbacks may be made against one proposal.
+        ///
+        /// - `proposal_id`: The ID of the proposal to claw back funds from.
+        /// - `amount`: The amount to recover.
+        /// - `reason`: The reason for the clawback.
+        ///
+        /// Emits `FundsClawedBack` event when successful, and `ProposalReconciled` if it
+        /// accounts for the rest of the amount disbursed.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::claw_back())]
+        pub fn claw_back(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            amount: T::Balance,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::EnforcementOrigin::ensure_origin(origin)?;
+
+            // Check if reason is valid
+            ensure!(!reason.is_empty(), Error::<T>::InvalidReason);
+            let bounded_reason: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(reason)
+                .map_err(|_| Error::<T>::InvalidReason)?;
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+            // Check if proposal has been paid out
+            ensure!(
+                matches!(
+                    proposal.status,
+                    ProposalStatus::Approved | ProposalStatus::Executed | ProposalStatus::ClawedBack
+                ),
+                Error::<T>::ProposalNotClawable
+            );
+
+            // Check the amount against the unspent balance
+            let mut reconciliation = Reconciliations::<T>::get(proposal_id);
+            let unspent = proposal
+                .disbursed
+                .saturating_sub(reconciliation.clawed_back)
+                .saturating_sub(reconciliation.reported);
+            ensure!(
+                !amount.is_zero() && amount <= unspent,
+                Error::<T>::InvalidClawbackAmount
+            );
+
+            // Get current block number
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            // Record the clawback
+            Clawbacks::<T>::try_mutate(proposal_id, |clawbacks| {
+                clawbacks
+                    .try_push(ClawbackRecord {
+                        amount,
+                        reason: bounded_reason.clone(),
+                        at: block_number,
+                    })
+                    .map_err(|_| Error::<T>::TooManyClawbacks)
+            })?;
+
+            // Recover the funds into the treasury
+            T::Assets::transfer(
+                proposal.asset.clone(),
+                &proposal.beneficiary,
+                &Self::treasury_account(),
+                amount,
+                Preservation::Expendable,
+            )?;
+
+            // Release the recovered amount, and whatever was never disbursed
+            let mut released = amount;
+            if proposal.status == ProposalStatus::Approved {
+                let undisbursed = proposal.amount.saturating_sub(proposal.disbursed);
+                released = released.saturating_add(undisbursed);
+            }
+            if let Some(period) = proposal.fiscal_period {
+                Self::release_budget(period, proposal.category, &proposal.asset, released);
+            }
+
+            // A proposal stopped mid-payment still has to account for what it received
+            if reconciliation.deadline.is_none() {
+                let deadline = Self::schedule_reporting_deadline(proposal_id, block_number);
+                reconciliation.deadline = Some(deadline);
+            }
+            reconciliation.clawed_back = reconciliation.clawed_back.saturating_add(amount);
+            let reconciled = reconciliation.reported.saturating_add(reconciliation.clawed_back) ==
+                proposal.disbursed;
+            if reconciled {
+                reconciliation.flagged = false;
+            }
+            Reconciliations::<T>::insert(proposal_id, reconciliation);
+
+            // Update proposal status
+            proposal.status = ProposalStatus::ClawedBack;
+            proposal.updated_at = block_number;
+            let beneficiary = proposal.beneficiary.clone();
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+
+            // Emit events
+            Self::deposit_event(Event::<T>::FundsClawedBack {
+                proposal_id,
+                beneficiary,
+                amount,
+                reason: bounded_reason,
+            });
+            if reconciled {
+                Self::deposit_event(Event::<T>::ProposalReconciled { proposal_id });
+            }
+
+            Ok(())
+        }
+
+        /// Open a participatory budgeting round in which citizens split `pot` across the
+        /// proposals entered into it.
+        ///
+        /// The dispatch origin must be `BudgetOrigin`.
+        ///
+        /// - `pot`: The amount the round can fund.
+        /// - `asset`: The asset the pot is held in.
+        /// - `voting`: How citizens vote.
+        /// - `duration`: The number of blocks proposals can be entered and voted on.
+        ///
+        /// Emits `RoundOpened` event when successful.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::open_round())]
+        pub fn open_round(
+            origin: OriginFor<T>,
+            pot: T::Balance,
+            asset: T::AssetId,
+            voting: RoundVoting,
+            duration: u64,
+        ) -> DispatchResult {
+            T::BudgetOrigin::ensure_origin(origin)?;
+
+            // Check if round is valid
+            ensure!(
+                !pot.is_zero() && duration > 0 && voting != RoundVoting::Points(0),
+                Error::<T>::InvalidRound
+            );
+            ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+            // Get current block number
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            let closes_at = block_number.saturating_add(duration);
+
+            // Get next round ID
+            let round_id = NextRoundId::<T>::get();
+            NextRoundId::<T>::put(round_id.saturating_add(1));
+
+            Rounds::<T>::insert(
+                round_id,
+                Round {
+                    pot,
+                    asset,
+                    voting,
+                    opened_at: block_number,
+                    closes_at,
+                    closed: false,
+                    spent: Zero::zero(),
+                },
+            );
+
+            // Emit event
+            Self::deposit_event(Event::<T>::RoundOpened { round_id, pot, closes_at });
+
+            Ok(())
+        }
+
+        /// Enter a pending proposal into an open participatory budgeting round.
+        ///
+        /// The dispatch origin must be signed by the proposal creator. The proposal must be
+        /// in the asset of the round and request no more than its pot. It is decided by the
+        /// close of the round instead of by an approver.
+        ///
+        /// - `round_id`: The ID of the round to enter.
+        /// - `proposal_id`: The ID of the proposal to enter.
+        ///
+        /// Emits `ProposalEnteredRound` event when successful.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::enter_round())]
+        pub fn enter_round(
+            origin: OriginFor<T>,
+            round_id: u32,
+            proposal_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get round
+            let round = Rounds::<T>::get(round_id).ok_or(Error::<T>::RoundDoesNotExist)?;
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            ensure!(block_number < round.closes_at, Error::<T>::RoundNotOpen);
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(proposal.creator == who, Error::<T>::NotProposalCreator);
+            ensure!(
+                proposal.status == ProposalStatus::Pending &&
+                    proposal.asset == round.asset &&
+                    proposal.amount <= round.pot,
+                Error::<T>::ProposalNotEligibleForRound
+            );
+
+            // Add the proposal to the round
+            RoundProposals::<T>::try_mutate(round_id, |proposals| {
+                proposals.try_push(proposal_id).map_err(|_| Error::<T>::TooManyRoundProposals)
+            })?;
+
+            // Update proposal status
+            proposal.status = ProposalStatus::InRound;
+            proposal.updated_at = block_number;
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::ProposalEnteredRound { round_id, proposal_id });
+
+            Ok(())
+        }
+
+        /// Allocate votes across the proposals of an open round.
+        ///
+        /// The dispatch origin must be signed by an eligible citizen. In a points round the
+        /// points allocated may not exceed those of the round; in an approval round each
+        /// proposal receives exactly one point. A new ballot replaces the citizen's earlier
+        /// one.
+        ///
+        /// - `round_id`: The ID of the round to vote in.
+        /// - `ballot`: The proposals voted for, with the points given to each.
+        ///
+        /// Emits `VotesAllocated` event when successful.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::allocate_votes(ballot.len() as u32))]
+        pub fn allocate_votes(
+            origin: OriginFor<T>,
+            round_id: u32,
+            ballot: Vec<(u64, u32)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Citizens::is_eligible(&who), Error::<T>::NotEligibleCitizen);
+
+            // Get round
+            let round = Rounds::<T>::get(round_id).ok_or(Error::<T>::RoundDoesNotExist)?;
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            ensure!(block_number < round.closes_at, Error::<T>::RoundNotOpen);
+
+            // Check if ballot is valid, rejecting ballots longer than a round first
+            let bounded_ballot: BoundedVec<(u64, u32), T::MaxRoundProposals> =
+                BoundedVec::try_from(ballot).map_err(|_| Error::<T>::InvalidBallot)?;
+            ensure!(!bounded_ballot.is_empty(), Error::<T>::InvalidBallot);
+            let entered = RoundProposals::<T>::get(round_id);
+            let mut total: u32 = 0;
+            for (index, (proposal_id, points)) in bounded_ballot.iter().enumerate() {
+                ensure!(
+                    *points > 0 &&
+                        entered.contains(proposal_id) &&
+                        !bounded_ballot[..index].iter().any(|(earlier, _)| earlier == proposal_id),
+                    Error::<T>::InvalidBallot
+                );
+                total = total.saturating_add(*points);
+            }
+            let valid_points = match round.voting {
+                RoundVoting::Points(max) => total <= max,
+                RoundVoting::Approval => bounded_ballot.iter().all(|(_, points)| *points == 1),
+            };
+            ensure!(valid_points, Error::<T>::InvalidBallot);
+
+            // Replace the earlier ballot in the scores
+            for (proposal_id, points) in RoundBallots::<T>::get(round_id, &who) {
+                RoundScores::<T>::mutate(round_id, proposal_id, |score| {
+                    *score = score.saturating_sub(points.into())
+                });
+            }
+            for (proposal_id, points) in bounded_ballot.iter() {
+                RoundScores::<T>::mutate(round_id, proposal_id, |score| {
+                    *score = score.saturating_add((*points).into())
+                });
+            }
+            let proposals = bounded_ballot.len() as u32;
+            RoundBallots::<T>::insert(round_id, &who, bounded_ballot);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::VotesAllocated { round_id, voter: who, proposals });
+
+            Ok(())
+        }
+
+        /// Close a round once voting has ended and fund its proposals.
+        ///
+        /// The dispatch origin must be signed. Proposals are taken from the highest score
+        /// down, ties going to the earlier proposal, and each one with votes that still fits
+        /// in the remaining pot and in the fiscal period and category budgets is approved. The
+        /// others are rejected.
+        ///
+        /// - `round_id`: The ID of the round to close.
+        ///
+        /// Emits `RoundProposalFunded` event for each funded proposal, and `RoundClosed`
+        /// event when successful.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::close_round(T::MaxRoundProposals::get()))]
+        pub fn close_round(origin: OriginFor<T>, round_id: u32) -> DispatchResultWithPostInfo {
+            let _who = ensure_signed(origin)?;
+
+            // Get round
+            let mut round = Rounds::<T>::get(round_id).ok_or(Error::<T>::RoundDoesNotExist)?;
+            ensure!(!round.closed, Error::<T>::RoundAlreadyClosed);
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            ensure!(block_number >= round.closes_at, Error::<T>::RoundStillOpen);
+
+            // Rank the proposals by score
+            let entered = RoundProposals::<T>::get(round_id);
+            let mut ranking: Vec<(u64, u64)> = entered
+                .iter()
+                .map(|proposal_id| (*proposal_id, RoundScores::<T>::get(round_id, proposal_id)))
+                .collect();
+            ranking.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+            // Fund greedily until the pot runs out
+            let mut funded: u32 = 0;
+            for (proposal_id, score) in ranking {
+                let Some(mut proposal) = BudgetProposals::<T>::get(proposal_id) else { continue };
+                let remaining = round.pot.saturating_sub(round.spent);
+                let approved = score > 0 &&
+                    proposal.amount <= remaining &&
+                    Self::do_approve(&mut proposal, block_number).is_ok();
+                if approved {
+                    round.spent = round.spent.saturating_add(proposal.amount);
+                    funded = funded.saturating_add(1);
+                    Self::deposit_event(Event::<T>::RoundProposalFunded {
+                        round_id,
+                        proposal_id,
+                        score,
+                    });
+                } else {
+                    proposal.status = ProposalStatus::Rejected;
+                    proposal.updated_at = block_number;
+                }
+                BudgetProposals::<T>::insert(proposal_id, proposal);
+            }
+
+            round.closed = true;
+            let spent = round.spent;
+            Rounds::<T>::insert(round_id, round);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::RoundClosed { round_id, funded, spent });
+
+            Ok(Some(T::WeightInfo::close_round(entered.len() as u32)).into())
+        }
+
+        /// Endorse a proposal that is seeking endorsements.
+        ///
+        /// The dispatch origin must be signed by an account other than the proposal creator.
+        /// Once the proposal has the endorsements it needs it becomes pending approval, or
+        /// goes to a referendum if it is large enough.
+        ///
+        /// - `proposal_id`: The ID of the proposal to endorse.
+        ///
+        /// Emits `ProposalEndorsed` event when successful, and `ProposalEndorsementsReached`
+        /// once the proposal has the endorsements it needs.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::endorse_proposal())]
+        pub fn endorse_proposal(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get proposal
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(
+                proposal.status == ProposalStatus::SeekingEndorsements,
+                Error::<T>::ProposalNotSeekingEndorsements
+            );
+            ensure!(proposal.creator != who, Error::<T>::CannotEndorseOwnProposal);
+
+            // Record the endorsement
+            let mut endorsers = Endorsements::<T>::get(proposal_id);
+            ensure!(!endorsers.contains(&who), Error::<T>::AlreadyEndorsed);
+            endorsers.try_push(who.clone()).map_err(|_| Error::<T>::TooManyEndorsements)?;
+            let endorsements = endorsers.len() as u32;
+            let required = Self::required_endorsements(proposal.amount);
+            Endorsements::<T>::insert(proposal_id, &endorsers);
+
+            Self::deposit_event(Event::<T>::ProposalEndorsed {
+                proposal_id,
+                endorser: who,
+                endorsements,
+                required,
+            });
+            if endorsements < required {
+                return Ok(());
+            }
+
+            // Send the proposal for review
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            let referendum = proposal.amount > T::ReferendumThreshold::get();
+            if referendum {
+                proposal.status = ProposalStatus::UnderReferendum;
+            } else {
+                proposal.status = ProposalStatus::Pending;
+            }
+            proposal.updated_at = block_number;
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+            if referendum {
+                T::Referenda::open_referendum(proposal_id)?;
+            }
+
+            Self::deposit_event(Event::<T>::ProposalEndorsementsReached { proposal_id, endorsers });
+            if referendum {
+                Self::deposit_event(Event::<T>::BudgetProposalReferendumOpened { proposal_id });
+            }
+
+            Ok(())
+        }
+
+        /// Declare a conflict of interest with another account.
+        ///
+        /// The dispatch origin must be signed by the approver. Proposals created by or paying
+        /// the related account can no longer be approved or rejected by them. Only
+        /// `EthicsOrigin` can remove a declaration.
+        ///
+        /// - `related`: The account the approver is related to.
+        /// - `relationship`: How the two accounts are related.
+        ///
+        /// Emits `ConflictDeclared` event when successful.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::declare_conflict())]
+        pub fn declare_conflict(
+            origin: OriginFor<T>,
+            related: T::AccountId,
+            relationship: Relationship,
+        ) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+            ensure!(
+                !ConflictDeclarations::<T>::contains_key(&approver, &related),
+                Error::<T>::ConflictAlreadyDeclared
+            );
+            Self::do_declare_conflict(approver, related, relationship, DeclarationSource::Approver)
+        }
+
+        /// Declare a conflict of interest on behalf of an approver, replacing any earlier
+        /// declaration between the two accounts.
+        ///
+        /// The dispatch origin must be `EthicsOrigin`.
+        ///
+        /// - `approver`: The approver with the conflict.
+        /// - `related`: The account the approver is related to.
+        /// - `relationship`: How the two accounts are related.
+        ///
+        /// Emits `ConflictDeclared` event when successful.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_conflict())]
+        pub fn set_conflict(
+            origin: OriginFor<T>,
+            approver: T::AccountId,
+            related: T::AccountId,
+            relationship: Relationship,
+        ) -> DispatchResult {
+            T::EthicsOrigin::ensure_origin(origin)?;
+            Self::do_declare_conflict(approver, related, relationship, DeclarationSource::Ethics)
+        }
+
+        /// Remove a declared conflict of interest.
+        ///
+        /// The dispatch origin must be `EthicsOrigin`.
+        ///
+        /// - `approver`: The approver with the conflict.
+        /// - `related`: The account the approver is related to.
+        ///
+        /// Emits `ConflictRemoved` event when successful.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::remove_conflict())]
+        pub fn remove_conflict(
+            origin: OriginFor<T>,
+            approver: T::AccountId,
+            related: T::AccountId,
+        ) -> DispatchResult {
+            T::EthicsOrigin::ensure_origin(origin)?;
+
+            ConflictDeclarations::<T>::take(&approver, &related)
+                .ok_or(Error::<T>::ConflictDoesNotExist)?;
+
+            Self::deposit_event(Event::<T>::ConflictRemoved { approver, related });
+
+            Ok(())
+        }
+
+        /// Recuse yourself from deciding a pending proposal.
+        ///
+        /// The dispatch origin must be signed by the approver. The recusal is recorded and the
+        /// approver can no longer approve or reject the proposal.
+        ///
+        /// - `proposal_id`: The ID of the proposal to recuse from.
+        ///
+        /// Emits `ApproverRecused` event when successful.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::recuse())]
+        pub fn recuse(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+
+            // Get proposal
+            let proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(
+                proposal.status == ProposalStatus::Pending,
+                Error::<T>::ProposalNotPending
+            );
+            ensure!(
+                !Recusals::<T>::contains_key(proposal_id, &approver),
+                Error::<T>::AlreadyRecused
+            );
+
+            // Record the recusal
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_number: u64 = current_block.try_into().map_err(|_| Error::<T>::InvalidPurpose)?;
+            Recusals::<T>::insert(proposal_id, &approver, block_number);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::ApproverRecused { proposal_id, approver });
+
+            Ok(())
+        }
+
+        /// Move funds from a government wallet into the account it pays proposals from.
+        ///
+        /// The dispatch origin must be signed by a registered government wallet. Proposals paid
+        /// from the wallet are transferred out of `wallet_account`, so it must be funded here
+        /// before they can be executed.
+        ///
+        /// - `asset`: The asset to move.
+        /// - `amount`: The amount to move.
+        ///
+        /// Emits `WalletFunded` event when successful.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::fund_wallet())]
+        pub fn fund_wallet(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            let wallet = ensure_signed(origin)?;
+
+            // Check if the caller is a government wallet
+            ensure!(T::Wallets::wallet_exists(&wallet), Error::<T>::UnknownWallet);
+
+            // Move the funds
+            T::Assets::transfer(
+                asset.clone(),
+                &wallet,
+                &Self::wallet_account(&wallet),
+                amount,
+                Preservation::Preserve,
+            )?;
+
+            // Emit event
+            Self::deposit_event(Event::<T>::WalletFunded { wallet, asset, amount });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Approve or reject a proposal according to the outcome of its referendum.
+        ///
+        /// A passed referendum is treated as rejected if the amount no longer fits the
+        /// fiscal period ceiling or category allocation.
+        pub fn conclude_referendum(proposal_id: u64, passed: bool) -> DispatchResult {
+            let mut proposal = BudgetProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(
+                proposal.status == ProposalStatus::UnderReferendum,
+                Error::<T>::ProposalNotUnderReferendum
+            );
+
+            let block_number: u64 = frame_system::Pallet::<T>::block_number()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidPurpose)?;
+
+            let approved = passed && Self::do_approve(&mut proposal, block_number).is_ok();
+            if !approved {
+                proposal.status = ProposalStatus::Rejected;
+                proposal.updated_at = block_number;
+            }
+            BudgetProposals::<T>::insert(proposal_id, proposal);
+
+            Self::deposit_event(Event::<T>::BudgetProposalReferendumConcluded {
+                proposal_id,
+                approved,
+            });
+
+            Ok(())
+        }
+
+        /// The number of endorsements a proposal requesting `amount` needs before it is
+        /// reviewed.
+        pub fn required_endorsements(amount: T::Balance) -> u32 {
+            let step = T::EndorsementStep::get();
+            let scaled = if step.is_zero() {
+                0
+            } else {
+                (amount / step).try_into().unwrap_or(u32::MAX)
+            };
+            T::BaseEndorsements::get()
+                .saturating_add(scaled)
+                .min(T::MaxEndorsements::get())
+        }
+
+        /// Whether `approver` has declared a conflict of interest with `account`.
+        pub fn has_conflict(approver: &T::AccountId, account: &T::AccountId) -> bool {
+            ConflictDeclarations::<T>::contains_key(approver, account)
+        }
+
+        /// The declared conflicts of interest of `approver`, with the accounts they are
+        /// related to.
+        pub fn conflict_declarations(
+            approver: &T::AccountId,
+        ) -> Vec<(T::AccountId, ConflictDeclaration)> {
+            ConflictDeclarations::<T>::iter_prefix(approver).collect()
+        }
+
+        /// The approvers who recused themselves from `proposal_id`.
+        pub fn recusals(proposal_id: u64) -> Vec<T::AccountId> {
+            Recusals::<T>::iter_key_prefix(proposal_id).collect()
+        }
+
+        /// Up to `limit` proposals matching `filter`, in ID order starting from `start`.
+        ///
+        /// At most `MAX_PROPOSALS_PAGE` proposals are returned. The next page starts after
+        /// the ID of the last proposal returned.
+        pub fn proposals(
+            filter: ProposalFilter<T::AccountId, T::Balance>,
+            start: u64,
+            limit: u32,
+        ) -> Vec<(u64, ProposalDetails<T::AccountId, T::Balance, T::AssetId>)> {
+            let limit = limit.min(MAX_PROPOSALS_PAGE) as usize;
+            (start..NextProposalId::<T>::get())
+                .filter_map(|proposal_id| {
+                    BudgetProposals::<T>::get(proposal_id).map(|proposal| (proposal_id, proposal))
+                })
+                .filter(|(_, proposal)| filter.matches(proposal))
+                .take(limit)
+                .collect()
+        }
+
+        /// Amounts requested in `asset` by pending, approved and rejected proposals.
+        ///
+        /// Pending covers every proposal still awaiting a decision, approved covers proposals
+        /// in their challenge period, being paid out, executed or clawed back, and rejected
+        /// covers rejected and vetoed proposals. Withdrawn and expired proposals are left out.
+        pub fn proposal_totals(asset: T::AssetId) -> ProposalTotals<T::Balance> {
+            let mut totals = ProposalTotals::<T::Balance>::default();
+            for proposal in BudgetProposals::<T>::iter_values().filter(|p| p.asset == asset) {
+                let total = match proposal.status {
+                    ProposalStatus::SeekingEndorsements |
+                    ProposalStatus::Pending |
+                    ProposalStatus::UnderReferendum |
+                    ProposalStatus::InRound => &mut totals.pending,
+                    ProposalStatus::AwaitingExecution |
+                    ProposalStatus::Approved |
+                    ProposalStatus::Executed |
+                    ProposalStatus::ClawedBack => &mut totals.approved,
+                    ProposalStatus::Rejected | ProposalStatus::Vetoed => &mut totals.rejected,
+                    ProposalStatus::Withdrawn | ProposalStatus::Expired => continue,
+                };
+                *total = total.saturating_add(proposal.amount);
+            }
+            totals
+        }
+
+        /// Check that `approver` has no conflict of interest with the creator or beneficiary
+        /// of `proposal` and has not recused from it.
+        fn ensure_can_decide(
+            approver: &T::AccountId,
+            proposal_id: u64,
+            proposal: &ProposalDetails<T::AccountId, T::Balance, T::AssetId>,
+        ) -> DispatchResult {
+            ensure!(
+                !Self::has_conflict(approver, &proposal.creator) &&
+                    !Self::has_conflict(approver, &proposal.beneficiary),
+                Error::<T>::ConflictOfInterest
+            );
+            ensure!(
+                !Recusals::<T>::contains_key(proposal_id, approver),
+                Error::<T>::RecusedFromProposal
+            );
+            Ok(())
+        }
+
+        /// Record a conflict of interest between `approver` and `related`.
+        fn do_declare_conflict(
+            approver: T::AccountId,
+            related: T::AccountId,
+            relationship: Relationship,
+            declared_by: DeclarationSource,
+        ) -> DispatchResult {
+            ensure!(approver != related, Error::<T>::InvalidConflict);
+
+            let declared_at: u64 = frame_system::Pallet::<T>::block_number()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidPurpose)?;
+            ConflictDeclarations::<T>::insert(
+                &approver,
+                &related,
+                ConflictDeclaration { relationship, declared_by, declared_at },
+            );
+
+            Self::deposit_event(Event::<T>::ConflictDeclared {
+                approver,
+                related,
+                relationship,
+                declared_by,
+            });
+
+            Ok(())
+        }
+
+        /// The treasury account paying proposals that do not name a government wallet.
+        pub fn treasury_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// The account holding the funds `wallet` spends on proposals.
+        pub fn wallet_account(wallet: &T::AccountId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(wallet)
+        }
+
+        /// The number of installments of an approved proposal due by `block_number`, counted
+        /// from the end of its challenge period, and the amount still to be paid for them.
+        pub fn payment_due(
+            proposal: &ProposalDetails<T::AccountId, T::Balance, T::AssetId>,
+            block_number: u64,
+        ) -> (u32, T::Balance) {
+            let Some(executable_at) = proposal.executable_at else {
+                return (proposal.installments_paid, Zero::zero());
+            };
+            let remaining = proposal.amount.saturating_sub(proposal.disbursed);
+
+            match proposal.schedule {
+                PaymentSchedule::LumpSum => (1, remaining),
+                PaymentSchedule::FromBlock(start) if block_number >= start => (1, remaining),
+                PaymentSchedule::FromBlock(_) => (proposal.installments_paid, Zero::zero()),
+                PaymentSchedule::Periodic { installments, interval } => {
+                    let elapsed = block_number.saturating_sub(executable_at) / interval.max(1);
+                    let due = u32::try_from(elapsed.saturating_add(1))
+                        .unwrap_or(u32::MAX)
+                        .min(installments);
+                    if due >= installments {
+                        // The final installment also settles any rounding remainder
+                        return (due, remaining);
+                    }
+                    let per_installment = proposal.amount / T::Balance::from(installments.max(1));
+                    let owed = per_installment.saturating_mul(T::Balance::from(due));
+                    (due, owed.saturating_sub(proposal.disbursed))
+                },
+            }
+        }
+
+        /// The fiscal period containing `block_number`.
+        pub fn fiscal_period_of(block_number: u64) -> u32 {
+            let length = T::FiscalPeriodLength::get().max(1);
+            u32::try_from(block_number / length).unwrap_or(u32::MAX)
+        }
+
+        /// The fiscal period containing the current block.
+        pub fn current_fiscal_period() -> u32 {
+            let block_number: u64 = frame_system::Pallet::<T>::block_number()
+                .try_into()
+                .unwrap_or(u64::MAX);
+            Self::fiscal_period_of(block_number)
+        }
+
+        /// Amount of `asset` still available for approval in `period`, or `None` if it has
+        /// no ceiling.
+        pub fn remaining_budget(period: u32, asset: T::AssetId) -> Option<T::Balance> {
+            let budget = FiscalBudgets::<T>::get(period, asset);
+            budget.ceiling.map(|ceiling| ceiling.saturating_sub(budget.committed))
+        }
+
+        /// Amount of `asset` still available for `category` in `period`, or `None` if it has
+        /// no allocation.
+        pub fn remaining_category_budget(
+            period: u32,
+            category: u32,
+            asset: T::AssetId,
+        ) -> Option<T::Balance> {
+            let budget = CategoryBudgets::<T>::get((period, category, asset));
+            budget.ceiling.map(|ceiling| ceiling.saturating_sub(budget.committed))
+        }
+
+        /// Commit the amount of `proposal` against the fiscal period of `block_number` and
+        /// start its challenge period.
+        fn do_approve(
+            proposal: &mut ProposalDetails<T::AccountId, T::Balance, T::AssetId>,
+            block_number: u64,
+        ) -> DispatchResult {
+            let period = Self::fiscal_period_of(block_number);
+            Self::commit_budget(period, proposal.category, &proposal.asset, proposal.amount)?;
+
+            proposal.status = ProposalStatus::AwaitingExecution;
+            proposal.fiscal_period = Some(period);
+            proposal.approved_at = Some(block_number);
+            proposal.executable_at = Some(block_number.saturating_add(T::ChallengePeriod::get()));
+            proposal.updated_at = block_number;
+            Ok(())
+        }
+
+        /// Add `amount` of `asset` to the committed totals of `period` and `category`,
+        /// respecting the period ceiling and the category allocation.
+        fn commit_budget(
+            period: u32,
+            category: u32,
+            asset: &T::AssetId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            // Check both limits before writing so a failure leaves neither total changed
+            let mut fiscal_budget = FiscalBudgets::<T>::get(period, asset);
+            fiscal_budget.commit(amount).ok_or(Error::<T>::BudgetCeilingExceeded)?;
+            let mut category_budget = CategoryBudgets::<T>::get((period, category, asset));
+            category_budget.commit(amount).ok_or(Error::<T>::CategoryAllocationExceeded)?;
+
+            FiscalBudgets::<T>::insert(period, asset, fiscal_budget);
+            CategoryBudgets::<T>::insert((period, category, asset), category_budget);
+            Ok(())
+        }
+
+        /// Remove `amount` of `asset` from the committed totals of `period` and `category`.
+        fn release_budget(period: u32, category: u32, asset: &T::AssetId, amount: T::Balance) {
+            FiscalBudgets::<T>::mutate(period, asset, |budget| budget.release(amount));
+            CategoryBudgets::<T>::mutate((period, category, asset), |budget| budget.release(amount));
+        }
+
+        /// Queue a proposal created at `created_at` for expiry once its lifetime passes.
+        fn schedule_expiry(proposal_id: u64, created_at: u64) {
+            let expires_at = created_at.saturating_add(T::ProposalLifetime::get());
+            ProposalExpiries::<T>::insert(expires_at, proposal_id, ());
+        }
+
+        /// Expire at most `MaxExpiriesPerBlock` proposals queued up to `block_number`, oldest
+        /// first, carrying over the rest to the next block.
+        ///
+        /// Returns the number of proposals and blocks processed.
+        fn process_expiries(block_number: u64) -> (u32, u32) {
+            let max = T::MaxExpiriesPerBlock::get();
+            let mut count = 0u32;
+            let mut visited = 0u32;
+            let mut at = ExpiryBacklog::<T>::take().unwrap_or(block_number);
+            loop {
+                visited = visited.saturating_add(1);
+                let remaining = max.saturating_sub(count) as usize;
+                for (proposal_id, ()) in ProposalExpiries::<T>::drain_prefix(at).take(remaining) {
+                    Self::expire_proposal(proposal_id, block_number);
+                    count = count.saturating_add(1);
+                }
+
+                // Check if expiries are left over
+                if ProposalExpiries::<T>::contains_prefix(at) {
+                    ExpiryBacklog::<T>::put(at);
+                    break;
+                }
+                if at >= block_number {
+                    break;
+                }
+                at = at.saturating_add(1);
+                if visited >= max {
+                    ExpiryBacklog::<T>::put(at);
+                    break;
+                }
+            }
+            (count, visited)
+        }
+
+        /// Queue a proposal paid at `paid_at` for a reconciliation check once its reporting period
+        /// passes, returning the deadline.
+        fn schedule_reporting_deadline(proposal_id: u64, paid_at: u64) -> u64 {
+            let deadline = paid_at.saturating_add(T::ReportingPeriod::get());
+            ReportingDeadlines::<T>::insert(deadline, proposal_id, ());
+            deadline
+        }
+
+        /// Check at most `MaxReportingDeadlinesPerBlock` reporting deadlines queued up to
+        /// `block_number`, oldest first, carrying over the rest to the next block.
+        ///
+        /// Returns the number of proposals and blocks processed.
+        fn process_reporting_deadlines(block_number: u64) -> (u32, u32) {
+            let max = T::MaxReportingDeadlinesPerBlock::get();
+            let mut count = 0u32;
+            let mut visited = 0u32;
+            let mut at = ReportingBacklog::<T>::take().unwrap_or(block_number);
+            loop {
+                visited = visited.saturating_add(1);
+                let remaining = max.saturating_sub(count) as usize;
+                for (proposal_id, ()) in ReportingDeadlines::<T>::drain_prefix(at).take(remaining) {
+                    Self::check_reconciliation(proposal_id);
+                    count = count.saturating_add(1);
+                }
+
+                // Check if deadlines are left over
+                if ReportingDeadlines::<T>::contains_prefix(at) {
+                    ReportingBacklog::<T>::put(at);
+                    break;
+                }
+                if at >= block_number {
+                    break;
+                }
+                at = at.saturating_add(1);
+                if visited >= max {
+                    ReportingBacklog::<T>::put(at);
+                    break;
+                }
+            }
+            (count, visited)
+        }
+
+        /// Amount disbursed for a proposal that is neither covered by expense reports nor
+        /// clawed back.
+        pub fn unreported_balance(proposal_id: u64) -> Option<T::Balance> {
+            let proposal = BudgetProposals::<T>::get(proposal_id)?;
+            let reconciliation = Reconciliations::<T>::get(proposal_id);
+            Some(
+                proposal
+                    .disbursed
+                    .saturating_sub(reconciliation.clawed_back)
+                    .saturating_sub(reconciliation.reported),
+            )
+        }
+
+        /// Flag a proposal whose reporting deadline passed with expenses unreported.
+        fn check_reconciliation(proposal_id: u64) {
+            let Some(proposal) = BudgetProposals::<T>::get(proposal_id) else { return };
+            Reconciliations::<T>::mutate(proposal_id, |reconciliation| {
+                let accounted =
+                    reconciliation.reported.saturating_add(reconciliation.clawed_back);
+                if accounted < proposal.disbursed {
+                    reconciliation.flagged = true;
+                    Self::deposit_event(Event::<T>::ProposalUnreconciled {
+                        proposal_id,
+                        disbursed: proposal.disbursed,
+                        reported: reconciliation.reported,
+                    });
+                }
+            });
+        }
+
+        /// Queue a payment of a recurring line at `block_number`.
+        fn schedule_recurring_payment(line_id: u32, block_number: u64) -> DispatchResult {
+            RecurringPayments::<T>::try_mutate(block_number, |queue| {
+                queue.try_push(line_id).map_err(|_| Error::<T>::RecurringQueueFull)
+            })?;
+            Ok(())
+        }
+
+        /// Make the payment of a recurring line due at `block_number` and schedule the next
+        /// one, pausing the line if the treasury cannot cover it.
+        fn pay_recurring_line(line_id: u32, block_number: u64) {
+            let Some(mut line) = RecurringLines::<T>::get(line_id) else { return };
+            if line.status != RecurringLineStatus::Active {
+                return;
+            }
+
+            match Self::try_recurring_payment(&line, block_number) {
+                Ok(()) => {
+                    line.payments_made = line.payments_made.saturating_add(1);
+                    Self::deposit_event(Event::<T>::RecurringPaymentMade {
+                        line_id,
+                        beneficiary: line.beneficiary.clone(),
+                        amount: line.amount,
+                    });
+                },
+                Err(reason) => {
+                    line.last_failure = Some((block_number, reason));
+                    Self::deposit_event(Event::<T>::RecurringPaymentFailed { line_id, reason });
+                    if reason == RecurringPaymentFailure::InsufficientFunds {
+                        line.status = RecurringLineStatus::Paused;
+                        RecurringLines::<T>::insert(line_id, line);
+                        Self::deposit_event(Event::<T>::RecurringLinePaused { line_id });
+                        return;
+                    }
+                },
+            }
+
+            // Schedule the next payment, or end the line
+            let next_payment = block_number.saturating_add(line.interval);
+            if next_payment > line.end {
+                line.status = RecurringLineStatus::Ended;
+                Self::deposit_event(Event::<T>::RecurringLineEnded { line_id });
+            } else if Self::schedule_recurring_payment(line_id, next_payment).is_ok() {
+                line.next_payment = next_payment;
+            } else {
+                let reason = RecurringPaymentFailure::QueueFull;
+                line.next_payment = next_payment;
+                line.last_failure = Some((block_number, reason));
+                line.status = RecurringLineStatus::Paused;
+                Self::deposit_event(Event::<T>::RecurringPaymentFailed { line_id, reason });
+                Self::deposit_event(Event::<T>::RecurringLinePaused { line_id });
+            }
+            RecurringLines::<T>::insert(line_id, line);
+        }
+
+        /// Commit and transfer one payment of a recurring line from the treasury.
+        fn try_recurring_payment(
+            line: &RecurringLine<T::AccountId, T::Balance, T::AssetId>,
+            block_number: u64,
+        ) -> Result<(), RecurringPaymentFailure> {
+            let treasury = Self::treasury_account();
+            let available = T::Assets::reducible_balance(
+                line.asset.clone(),
+                &treasury,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            if available < line.amount {
+                return Err(RecurringPaymentFailure::InsufficientFunds);
+            }
+
+            let period = Self::fiscal_period_of(block_number);
+            Self::commit_budget(period, line.category, &line.asset, line.amount)
+                .map_err(|_| RecurringPaymentFailure::BudgetExceeded)?;
+
+            if T::Assets::transfer(
+                line.asset.clone(),
+                &treasury,
+                &line.beneficiary,
+                line.amount,
+                Preservation::Expendable,
+            )
+            .is_err()
+            {
+                Self::release_budget(period, line.category, &line.asset, line.amount);
+                return Err(RecurringPaymentFailure::TransferFailed);
+            }
+            Ok(())
+        }
+
+        /// Mark a proposal as expired if it is still waiting for a decision.
+        fn expire_proposal(proposal_id: u64, block_number: u64) {
+            BudgetProposals::<T>::mutate(proposal_id, |maybe_proposal| {
+                if let Some(proposal) = maybe_proposal {
+                    if !matches!(
+                        proposal.status,
+                        ProposalStatus::Pending | ProposalStatus::SeekingEndorsements
+                    ) {
+                        return;
+                    }
+                    proposal.status = ProposalStatus::Expired;
+                    proposal.updated_at = block_number;
+
+                    Self::deposit_event(Event::<T>::BudgetProposalExpired { proposal_id });
+                }
+            });
+        }
     }
 }
 #[cfg(test)]
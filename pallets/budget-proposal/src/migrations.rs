@@ -0,0 +1,34 @@
+//! Storage migrations of the budget proposal pallet.
+
+/// Migrates the pallet from storage version 0 to 1.
+pub mod v1 {
+    use crate::{BudgetProposals, Config, Pallet};
+    use frame::{
+        deps::frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade},
+        prelude::*,
+    };
+
+    /// Removes the proposals stored before version 1.
+    ///
+    /// Version 0 proposals hold a bare `u128` amount with no asset, beneficiary, budget
+    /// category or fiscal period, so they cannot be carried over to the current
+    /// `ProposalDetails`. They are dropped and the pallet starts from an empty proposal set;
+    /// `NextProposalId` is kept so that no proposal ID is ever reused.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let removed = BudgetProposals::<T>::clear(u32::MAX, None);
+            T::DbWeight::get().reads_writes(removed.loops.into(), removed.unique.into())
+        }
+    }
+
+    /// `InnerMigrateV0ToV1` run only when the on-chain storage version is 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,5 +1,13 @@
 use crate as budget_proposal_pallet;
+use frame::deps::frame_support::{
+    traits::{
+        fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+        fungibles, AsEnsureOriginWithArg, SortedMembers,
+    },
+    PalletId,
+};
 use frame::{prelude::*, runtime::prelude::*, testing_prelude::*};
+use polkadot_sdk::{pallet_assets, pallet_balances};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -25,24 +33,188 @@ mod runtime {
 
     #[runtime::pallet_index(1)]
     pub type BudgetProposalPallet = budget_proposal_pallet;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(3)]
+    pub type Assets = pallet_assets;
 }
 
 // System pallet configuration
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+// Balances pallet configuration
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type AccountStore = System;
+}
+
+// Assets pallet configuration
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Balance = u128;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+}
+
+/// Assets a proposal can request: the native token or an asset of `pallet_assets`.
+pub type AssetKind = NativeOrWithId<u32>;
+
+/// The native token and `pallet_assets` behind a single `fungibles` interface.
+pub type NativeAndAssets = UnionOf<Balances, Assets, NativeFromLeft, AssetKind, u64>;
+
+/// ID of the stablecoin created by `new_test_ext`.
+pub const STABLE: u32 = 1;
+
+/// The only government wallet known to the mock runtime.
+pub const WALLET: u64 = 100;
+
+pub struct MockWallets;
+impl budget_proposal_pallet::GovernmentWallets<u64, u128> for MockWallets {
+    fn wallet_exists(wallet: &u64) -> bool {
+        *wallet == WALLET
+    }
+
+    fn debit(wallet: &u64, amount: u128) -> DispatchResult {
+        ensure!(*wallet == WALLET, DispatchError::Other("Unknown wallet"));
+        let balance = WalletLedger::get();
+        ensure!(balance >= amount, DispatchError::Other("Insufficient balance"));
+        WalletLedger::set(balance - amount);
+        Ok(())
+    }
+}
+
+// Accounts allowed to approve and reject proposals.
+pub struct Approvers;
+impl SortedMembers<u64> for Approvers {
+    fn sorted_members() -> Vec<u64> {
+        vec![2, 3, 4, 5, 6, 7]
+    }
+}
+
+// An account that is not a registered citizen.
+pub const NON_CITIZEN: u64 = 99;
+
+pub struct MockCitizens;
+impl budget_proposal_pallet::CitizenRegistry<u64> for MockCitizens {
+    fn is_eligible(who: &u64) -> bool {
+        *who != NON_CITIZEN
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register(_who: &u64) {}
+}
+
+parameter_types! {
+    pub const BudgetPalletId: PalletId = PalletId(*b"py/budgt");
+    /// Amount above which proposals go to referendum, lowered by referendum tests.
+    pub static ReferendumThreshold: u128 = u128::MAX;
+    /// Endorsements every proposal needs, raised by endorsement tests.
+    pub static BaseEndorsements: u32 = 0;
+    /// Amount per additional endorsement, set by endorsement tests.
+    pub static EndorsementStep: u128 = 0;
+    /// Balance on the ledger of `WALLET`.
+    pub static WalletLedger: u128 = 0;
+    /// Proposals put to referendum through `MockReferenda`, in order.
+    pub static OpenedReferenda: Vec<u64> = Vec::new();
+}
+
+pub struct MockReferenda;
+impl budget_proposal_pallet::ReferendumProvider for MockReferenda {
+    fn open_referendum(proposal_id: u64) -> DispatchResult {
+        // Like the runtime provider, only vote on proposals already stored for a referendum
+        let under_referendum = budget_proposal_pallet::BudgetProposals::<Test>::get(proposal_id)
+            .is_some_and(|proposal| {
+                proposal.status == budget_proposal_pallet::ProposalStatus::UnderReferendum
+            });
+        ensure!(under_referendum, DispatchError::Other("Proposal not under referendum"));
+        OpenedReferenda::mutate(|referenda| referenda.push(proposal_id));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NativeAssetHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl budget_proposal_pallet::BenchmarkHelper<AssetKind, u64> for NativeAssetHelper {
+    fn asset() -> AssetKind {
+        AssetKind::Native
+    }
+
+    fn wallet() -> u64 {
+        WALLET
+    }
 }
 
 // BudgetProposal pallet configuration
 impl budget_proposal_pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = pallet_name::weights::SubstrateWeight<Test>;
+    type WeightInfo = crate::weights::SubstrateWeight<Test>;
+    type Balance = u128;
+    type AssetId = AssetKind;
+    type Assets = NativeAndAssets;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = NativeAssetHelper;
+    type MaxRevisions = ConstU32<3>;
+    type ProposalLifetime = ConstU64<100>;
+    type MaxExpiriesPerBlock = ConstU32<3>;
+    type BudgetOrigin = frame_system::EnsureRoot<u64>;
+    type FiscalPeriodLength = ConstU64<1000>;
+    type ChallengePeriod = ConstU64<10>;
+    type OversightOrigin = frame_system::EnsureRoot<u64>;
+    type EnforcementOrigin = frame_system::EnsureRoot<u64>;
+    type MaxClawbacks = ConstU32<3>;
+    type Citizens = MockCitizens;
+    type MaxRoundProposals = ConstU32<5>;
+    type BaseEndorsements = BaseEndorsements;
+    type EndorsementStep = EndorsementStep;
+    type MaxEndorsements = ConstU32<5>;
+    type EthicsOrigin = frame_system::EnsureRoot<u64>;
+    type MaxRecurringPaymentsPerBlock = ConstU32<3>;
+    type ReportingPeriod = ConstU64<50>;
+    type MaxExpenseItems = ConstU32<5>;
+    type MaxReportingDeadlinesPerBlock = ConstU32<3>;
+    type Referenda = MockReferenda;
+    type ReferendumThreshold = ReferendumThreshold;
+    type Wallets = MockWallets;
+    type ApproverOrigin = frame_system::EnsureSignedBy<Approvers, u64>;
+    type PalletId = BudgetPalletId;
 }
 
-// Test externalities initialization
+// Test externalities initialization with a single budget category (ID 0) and a
+// stablecoin (ID `STABLE`)
 pub fn new_test_ext() -> TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut ext: TestExternalities = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap()
-        .into()
-}
\ No newline at end of file
+        .into();
+    ext.execute_with(|| {
+        assert_ok!(BudgetProposalPallet::register_category(
+            RuntimeOrigin::root(),
+            b"General".to_vec()
+        ));
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), STABLE, 1, true, 1));
+    });
+    ext
+}
+
+// Advance to block `n`, running the pallet's `on_initialize` hook for each block
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        BudgetProposalPallet::on_initialize(next);
+    }
+}
+
+// Mint `amount` of `asset` into `who`
+pub fn fund(who: u64, asset: AssetKind, amount: u128) {
+    assert_ok!(<NativeAndAssets as fungibles::Mutate<u64>>::mint_into(asset, &who, amount));
+}
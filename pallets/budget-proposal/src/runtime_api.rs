@@ -0,0 +1,44 @@
+//! Runtime API definition for the budget-proposal pallet.
+
+use crate::{ConflictDeclaration, FiscalBudget, ProposalDetails, ProposalFilter, ProposalTotals};
+use codec::Codec;
+use frame::deps::sp_api;
+use scale_info::prelude::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// API for querying budget proposal state.
+    pub trait BudgetProposalApi<AccountId, AssetId, Balance>
+    where
+        AccountId: Codec,
+        AssetId: Codec,
+        Balance: Codec,
+    {
+        /// The fiscal period containing the current block.
+        fn current_fiscal_period() -> u32;
+
+        /// Amount of `asset` still available for approval in `period`, or `None` if it has
+        /// no ceiling.
+        fn remaining_budget(period: u32, asset: AssetId) -> Option<Balance>;
+
+        /// Allocation and committed total of `category` in `period` for `asset`.
+        fn category_budget(period: u32, category: u32, asset: AssetId) -> FiscalBudget<Balance>;
+
+        /// The declared conflicts of interest of `approver`, with the accounts they are
+        /// related to.
+        fn conflict_declarations(approver: AccountId) -> Vec<(AccountId, ConflictDeclaration)>;
+
+        /// The approvers who recused themselves from `proposal_id`.
+        fn recusals(proposal_id: u64) -> Vec<AccountId>;
+
+        /// Up to `limit` proposals matching `filter`, in ID order starting from `start`.
+        /// The next page starts after the ID of the last proposal returned.
+        fn proposals(
+            filter: ProposalFilter<AccountId, Balance>,
+            start: u64,
+            limit: u32,
+        ) -> Vec<(u64, ProposalDetails<AccountId, Balance, AssetId>)>;
+
+        /// Amounts requested in `asset` by pending, approved and rejected proposals.
+        fn proposal_totals(asset: AssetId) -> ProposalTotals<Balance>;
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{mock::*, Error, Event};
+use crate::{
+    migrations, mock::*, BudgetCategories, BudgetProposals, CategoryBudgets, Clawbacks,
+    ConflictDeclaration, ConflictDeclarations, DeclarationSource, Endorsements, Error, Event,
+    ExpenseItem, ExpenseReports, ExpiryBacklog, FiscalBudgets, NextProposalId, PaymentSchedule,
+    ProposalExpiries, ProposalFilter, ProposalRevisions, ProposalStatus, ProposalTotals,
+    Reconciliations, RecurringLineStatus, RecurringLines, RecurringPaymentFailure,
+    RecurringPayments, Recusals, Relationship, ReportingBacklog, ReportingDeadlines,
+    RoundBallots, RoundProposals, RoundScores, RoundVoting, Rounds,
+};
 use frame::deps::sp_runtime;
 use frame::testing_prelude::*;
 
@@ -12,7 +20,12 @@ fn it_works_for_create_proposal() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Community development project".to_vec()
+            b"Community development project".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Check that the event was emitted correctly
@@ -20,8 +33,11 @@ fn it_works_for_create_proposal() {
             Event::BudgetProposalCreated {
                 proposal_id: 0,
                 creator: 1,
+                beneficiary: 1,
                 amount: 1000,
                 purpose: BoundedVec::try_from(b"Community development project".to_vec()).unwrap(),
+                category: 0,
+                asset: AssetKind::Native,
             }
             .into(),
         );
@@ -38,7 +54,12 @@ fn it_works_for_approve_proposal() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             500,
-            b"Infrastructure upgrade".to_vec()
+            b"Infrastructure upgrade".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Then approve it
@@ -68,7 +89,12 @@ fn it_works_for_reject_proposal() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             2000,
-            b"Marketing campaign".to_vec()
+            b"Marketing campaign".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Then reject it
@@ -100,7 +126,12 @@ fn create_proposal_fails_for_empty_purpose() {
             BudgetProposalPallet::create_proposal(
                 RuntimeOrigin::signed(1),
                 1000,
-                b"".to_vec() // Empty purpose
+                b"".to_vec(), // Empty purpose
+                0,
+                AssetKind::Native,
+                1,
+                None,
+                PaymentSchedule::LumpSum
             ),
             Error::<Test>::InvalidPurpose
         );
@@ -120,7 +151,12 @@ fn create_proposal_fails_for_too_long_purpose() {
             BudgetProposalPallet::create_proposal(
                 RuntimeOrigin::signed(1),
                 1000,
-                long_purpose
+                long_purpose,
+                0,
+                AssetKind::Native,
+                1,
+                None,
+                PaymentSchedule::LumpSum
             ),
             Error::<Test>::InvalidPurpose
         );
@@ -137,7 +173,12 @@ fn reject_proposal_fails_for_empty_reason() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Try to reject with empty reason
@@ -162,7 +203,12 @@ fn reject_proposal_fails_for_too_long_reason() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Create reason that exceeds 100 bytes
@@ -189,7 +235,12 @@ fn create_proposal_fails_for_unsigned_origin() {
             BudgetProposalPallet::create_proposal(
                 RuntimeOrigin::none(), // No signature
                 1000,
-                b"Some purpose".to_vec()
+                b"Some purpose".to_vec(),
+                0,
+                AssetKind::Native,
+                1,
+                None,
+                PaymentSchedule::LumpSum
             ),
             sp_runtime::traits::BadOrigin
         );
@@ -240,7 +291,7 @@ fn approve_proposal_fails_for_non_existent_proposal() {
         // Try to approve proposal that doesn't exist
         assert_noop!(
             BudgetProposalPallet::approve_proposal(
-                RuntimeOrigin::signed(1),
+                RuntimeOrigin::signed(2),
                 999 // Non-existent proposal ID
             ),
             Error::<Test>::ProposalDoesNotExist
@@ -248,6 +299,40 @@ fn approve_proposal_fails_for_non_existent_proposal() {
     });
 }
 
+// Test only approvers can approve or reject a proposal
+#[test]
+fn approve_and_reject_require_approver_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Account 1 is not an approver in the mock
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(1), 0),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_noop!(
+            BudgetProposalPallet::reject_proposal(
+                RuntimeOrigin::signed(1),
+                0,
+                b"Not needed".to_vec()
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Pending);
+    });
+}
+
 // Test reject_proposal fails for non-existent proposal
 #[test]
 fn reject_proposal_fails_for_non_existent_proposal() {
@@ -257,7 +342,7 @@ fn reject_proposal_fails_for_non_existent_proposal() {
         // Try to reject proposal that doesn't exist
         assert_noop!(
             BudgetProposalPallet::reject_proposal(
-                RuntimeOrigin::signed(1),
+                RuntimeOrigin::signed(2),
                 999, // Non-existent proposal ID
                 b"Some reason".to_vec()
             ),
@@ -275,7 +360,12 @@ fn approve_proposal_fails_for_already_approved() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         assert_ok!(BudgetProposalPallet::approve_proposal(
@@ -304,7 +394,12 @@ fn reject_proposal_fails_for_already_rejected() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         assert_ok!(BudgetProposalPallet::reject_proposal(
@@ -335,7 +430,12 @@ fn reject_proposal_fails_for_approved_proposal() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         assert_ok!(BudgetProposalPallet::approve_proposal(
@@ -365,7 +465,12 @@ fn approve_proposal_fails_for_rejected_proposal() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         assert_ok!(BudgetProposalPallet::reject_proposal(
@@ -394,21 +499,36 @@ fn multiple_proposals_have_sequential_ids() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"First proposal".to_vec()
+            b"First proposal".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Create second proposal - should get ID 1  
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(2),
             2000,
-            b"Second proposal".to_vec()
+            b"Second proposal".to_vec(),
+            0,
+            AssetKind::Native,
+            2,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Create third proposal - should get ID 2
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(3),
             3000,
-            b"Third proposal".to_vec()
+            b"Third proposal".to_vec(),
+            0,
+            AssetKind::Native,
+            3,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Verify all proposals can be approved with correct IDs
@@ -434,21 +554,36 @@ fn different_users_can_create_proposals() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             500,
-            b"User 1 project".to_vec()
+            b"User 1 project".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // User 2 creates proposal
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(2),
             1500,
-            b"User 2 project".to_vec()
+            b"User 2 project".to_vec(),
+            0,
+            AssetKind::Native,
+            2,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // User 3 creates proposal
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(3),
             2500,
-            b"User 3 project".to_vec()
+            b"User 3 project".to_vec(),
+            0,
+            AssetKind::Native,
+            3,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // All should have different IDs and work
@@ -470,7 +605,12 @@ fn it_works_for_max_length_purpose() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            max_purpose
+            max_purpose,
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Should work and emit event
@@ -478,8 +618,11 @@ fn it_works_for_max_length_purpose() {
             Event::BudgetProposalCreated {
                 proposal_id: 0,
                 creator: 1,
+                beneficiary: 1,
                 amount: 1000,
                 purpose: BoundedVec::try_from(b"A".repeat(200)).unwrap(),
+                category: 0,
+                asset: AssetKind::Native,
             }
             .into(),
         );
@@ -496,7 +639,12 @@ fn it_works_for_max_length_reason() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             1000,
-            b"Some purpose".to_vec()
+            b"Some purpose".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Create reason with exactly 100 bytes (maximum)
@@ -530,7 +678,12 @@ fn it_works_for_large_amounts() {
         assert_ok!(BudgetProposalPallet::create_proposal(
             RuntimeOrigin::signed(1),
             u128::MAX, // Maximum u128 value
-            b"Large budget project".to_vec()
+            b"Large budget project".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
         ));
         
         // Should work and emit event with correct amount
@@ -538,10 +691,2960 @@ fn it_works_for_large_amounts() {
             Event::BudgetProposalCreated {
                 proposal_id: 0,
                 creator: 1,
+                beneficiary: 1,
                 amount: u128::MAX,
                 purpose: BoundedVec::try_from(b"Large budget project".to_vec()).unwrap(),
+                category: 0,
+                asset: AssetKind::Native,
+            }
+            .into(),
+        );
+    });
+}
+
+// Test creator can update a pending proposal and the revision is kept
+#[test]
+fn it_works_for_update_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // First create a proposal
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Edit it at a later block
+        System::set_block_number(3);
+        assert_ok!(BudgetProposalPallet::update_proposal(
+            RuntimeOrigin::signed(1),
+            0,
+            1200,
+            b"Road and bridge repairs".to_vec()
+        ));
+
+        System::assert_last_event(
+            Event::BudgetProposalUpdated {
+                proposal_id: 0,
+                amount: 1200,
+                purpose: BoundedVec::try_from(b"Road and bridge repairs".to_vec()).unwrap(),
+                revision: 1,
             }
             .into(),
         );
+
+        // Proposal holds the new values
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.amount, 1200);
+        assert_eq!(proposal.updated_at, 3);
+
+        // Revision history holds the previous values
+        let revisions = ProposalRevisions::<Test>::get(0);
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].amount, 1000);
+        assert_eq!(revisions[0].purpose.to_vec(), b"Road repairs".to_vec());
+        assert_eq!(revisions[0].revised_at, 3);
+    });
+}
+
+// Test only the creator can update a proposal
+#[test]
+fn update_proposal_fails_for_non_creator() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        assert_noop!(
+            BudgetProposalPallet::update_proposal(
+                RuntimeOrigin::signed(2),
+                0,
+                5000,
+                b"Something else".to_vec()
+            ),
+            Error::<Test>::NotProposalCreator
+        );
+    });
+}
+
+// Test approved proposals can no longer be updated
+#[test]
+fn update_proposal_fails_for_approved_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        assert_noop!(
+            BudgetProposalPallet::update_proposal(
+                RuntimeOrigin::signed(1),
+                0,
+                5000,
+                b"Road repairs".to_vec()
+            ),
+            Error::<Test>::ProposalNotPending
+        );
+    });
+}
+
+// Test revision history is bounded
+#[test]
+fn update_proposal_fails_when_revision_limit_reached() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Mock allows three revisions
+        for amount in 1..=3 {
+            assert_ok!(BudgetProposalPallet::update_proposal(
+                RuntimeOrigin::signed(1),
+                0,
+                amount,
+                b"Road repairs".to_vec()
+            ));
+        }
+
+        assert_noop!(
+            BudgetProposalPallet::update_proposal(
+                RuntimeOrigin::signed(1),
+                0,
+                4,
+                b"Road repairs".to_vec()
+            ),
+            Error::<Test>::TooManyRevisions
+        );
+    });
+}
+
+// Test creator can withdraw a pending proposal
+#[test]
+fn it_works_for_withdraw_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        assert_ok!(BudgetProposalPallet::withdraw_proposal(RuntimeOrigin::signed(1), 0));
+
+        System::assert_last_event(
+            Event::BudgetProposalWithdrawn { proposal_id: 0, creator: 1 }.into(),
+        );
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Withdrawn);
+
+        // Withdrawn proposals cannot be approved
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotPending
+        );
+    });
+}
+
+// Test only the creator can withdraw a proposal
+#[test]
+fn withdraw_proposal_fails_for_non_creator() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        assert_noop!(
+            BudgetProposalPallet::withdraw_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NotProposalCreator
+        );
+    });
+}
+
+// Test pending proposals expire once their lifetime passes
+#[test]
+fn pending_proposal_expires_after_lifetime() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Lifetime is 100 blocks in the mock
+        assert_eq!(ProposalExpiries::<Test>::iter_key_prefix(101).collect::<Vec<_>>(), vec![0]);
+
+        // Still pending right before expiry
+        run_to_block(100);
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Pending);
+
+        run_to_block(101);
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Expired);
+        assert_eq!(proposal.updated_at, 101);
+        assert!(!ProposalExpiries::<Test>::contains_prefix(101));
+        System::assert_last_event(Event::BudgetProposalExpired { proposal_id: 0 }.into());
+
+        // Expired proposals can no longer be approved
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotPending
+        );
+    });
+}
+
+// Test decided proposals are left untouched by the expiry queue
+#[test]
+fn decided_proposal_does_not_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        run_to_block(101);
+        assert_eq!(
+            BudgetProposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::AwaitingExecution
+        );
+        assert!(!System::events().iter().any(|record| {
+            record.event == Event::BudgetProposalExpired { proposal_id: 0 }.into()
+        }));
+    });
+}
+
+// Test expiries beyond the per-block limit are carried over to the next block
+#[test]
+fn expiries_beyond_limit_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Mock expires three proposals per block
+        for _ in 0..4 {
+            assert_ok!(BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                1000,
+                b"Road repairs".to_vec(),
+                0,
+                AssetKind::Native,
+                1,
+                None,
+                PaymentSchedule::LumpSum
+            ));
+        }
+
+        run_to_block(101);
+        let expired = (0..4)
+            .filter(|id| {
+                BudgetProposals::<Test>::get(id).unwrap().status == ProposalStatus::Expired
+            })
+            .count();
+        assert_eq!(expired, 3);
+        assert_eq!(ExpiryBacklog::<Test>::get(), Some(101));
+
+        // The leftover proposal expires in the next block
+        run_to_block(102);
+        for proposal_id in 0..4 {
+            let proposal = BudgetProposals::<Test>::get(proposal_id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Expired);
+        }
+        assert!(!ProposalExpiries::<Test>::contains_prefix(101));
+        assert_eq!(ExpiryBacklog::<Test>::get(), None);
+    });
+}
+
+// Test budget origin can set a fiscal period ceiling
+#[test]
+fn it_works_for_set_budget_ceiling() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            5000
+        ));
+
+        System::assert_last_event(Event::BudgetCeilingSet { period: 0, asset: AssetKind::Native, ceiling: 5000 }.into());
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).ceiling, Some(5000));
+        assert_eq!(BudgetProposalPallet::remaining_budget(0, AssetKind::Native), Some(5000));
+    });
+}
+
+// Test signed accounts cannot set a fiscal period ceiling
+#[test]
+fn set_budget_ceiling_fails_for_signed_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            BudgetProposalPallet::set_budget_ceiling(
+                RuntimeOrigin::signed(1),
+                0,
+                AssetKind::Native,
+                5000
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+    });
+}
+
+// Test approvals are committed against the current fiscal period
+#[test]
+fn approve_proposal_commits_against_fiscal_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            5000
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            2000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().fiscal_period, Some(0));
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 2000);
+        assert_eq!(BudgetProposalPallet::remaining_budget(0, AssetKind::Native), Some(3000));
     });
-}
\ No newline at end of file
+}
+
+// Test approvals exceeding the fiscal period ceiling are rejected
+#[test]
+fn approve_proposal_fails_when_ceiling_exceeded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            5000
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            3000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            2001,
+            b"School roof".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::BudgetCeilingExceeded
+        );
+        assert_eq!(BudgetProposals::<Test>::get(1).unwrap().status, ProposalStatus::Pending);
+    });
+}
+
+// Test each fiscal period has its own ceiling
+#[test]
+fn fiscal_periods_are_tracked_separately() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            1000
+        ));
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            1,
+            AssetKind::Native,
+            1000
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        assert_eq!(BudgetProposalPallet::remaining_budget(0, AssetKind::Native), Some(0));
+
+        // Period 1 starts at block 1000 in the mock
+        System::set_block_number(1000);
+        assert_eq!(BudgetProposalPallet::current_fiscal_period(), 1);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"School roof".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 1));
+        assert_eq!(BudgetProposals::<Test>::get(1).unwrap().fiscal_period, Some(1));
+        assert_eq!(BudgetProposalPallet::remaining_budget(1, AssetKind::Native), Some(0));
+    });
+}
+
+// Test budget origin can register a category
+#[test]
+fn it_works_for_register_category() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Category 0 is registered by the mock
+        assert_ok!(BudgetProposalPallet::register_category(
+            RuntimeOrigin::root(),
+            b"Health".to_vec()
+        ));
+
+        System::assert_last_event(
+            Event::BudgetCategoryRegistered {
+                category: 1,
+                name: BoundedVec::try_from(b"Health".to_vec()).unwrap(),
+            }
+            .into(),
+        );
+        assert!(BudgetCategories::<Test>::contains_key(1));
+
+        // Signed accounts cannot register categories
+        assert_noop!(
+            BudgetProposalPallet::register_category(RuntimeOrigin::signed(1), b"Roads".to_vec()),
+            sp_runtime::traits::BadOrigin
+        );
+    });
+}
+
+// Test proposals must name an existing category
+#[test]
+fn create_proposal_fails_for_unknown_category() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                1000,
+                b"Road repairs".to_vec(),
+                7,
+                AssetKind::Native,
+                1,
+                None,
+                PaymentSchedule::LumpSum
+            ),
+            Error::<Test>::CategoryDoesNotExist
+        );
+    });
+}
+
+// Test allocations can only be set for existing categories
+#[test]
+fn set_category_allocation_fails_for_unknown_category() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            BudgetProposalPallet::set_category_allocation(
+                RuntimeOrigin::root(),
+                0,
+                7,
+                AssetKind::Native,
+                1000
+            ),
+            Error::<Test>::CategoryDoesNotExist
+        );
+    });
+}
+
+// Test approvals count against the category allocation
+#[test]
+fn approve_proposal_fails_when_category_allocation_exceeded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::register_category(
+            RuntimeOrigin::root(),
+            b"Health".to_vec()
+        ));
+        assert_ok!(BudgetProposalPallet::set_category_allocation(
+            RuntimeOrigin::root(),
+            0,
+            1,
+            AssetKind::Native,
+            1500
+        ));
+        System::assert_last_event(
+            Event::CategoryAllocationSet {
+                period: 0,
+                category: 1,
+                asset: AssetKind::Native,
+                allocation: 1500,
+            }.into(),
+        );
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Clinic supplies".to_vec(),
+            1,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Ambulance".to_vec(),
+            1,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::CategoryAllocationExceeded
+        );
+
+        // Other categories are unaffected
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 2));
+
+        assert_eq!(CategoryBudgets::<Test>::get((0, 1, AssetKind::Native)).committed, 1000);
+        assert_eq!(CategoryBudgets::<Test>::get((0, 0, AssetKind::Native)).committed, 1000);
+        assert_eq!(BudgetProposalPallet::remaining_category_budget(0, 1, AssetKind::Native), Some(500));
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 2000);
+    });
+}
+
+// Test proposals can request funds in the stablecoin
+#[test]
+fn it_works_for_stablecoin_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            750,
+            b"Imported medicine".to_vec(),
+            0,
+            AssetKind::WithId(STABLE),
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        System::assert_last_event(
+            Event::BudgetProposalCreated {
+                proposal_id: 0,
+                creator: 1,
+                beneficiary: 1,
+                amount: 750,
+                purpose: BoundedVec::try_from(b"Imported medicine".to_vec()).unwrap(),
+                category: 0,
+                asset: AssetKind::WithId(STABLE),
+            }
+            .into(),
+        );
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().asset, AssetKind::WithId(STABLE));
+    });
+}
+
+// Test proposals cannot request an asset that does not exist
+#[test]
+fn create_proposal_fails_for_unknown_asset() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                750,
+                b"Imported medicine".to_vec(),
+                0,
+                AssetKind::WithId(42),
+                1,
+                None,
+                PaymentSchedule::LumpSum
+            ),
+            Error::<Test>::UnknownAsset
+        );
+    });
+}
+
+// Test fiscal ceilings are tracked per asset
+#[test]
+fn fiscal_ceilings_are_tracked_per_asset() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            1000
+        ));
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::WithId(STABLE),
+            500
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            600,
+            b"Imported medicine".to_vec(),
+            0,
+            AssetKind::WithId(STABLE),
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Native ceiling is fully used, the stablecoin request exceeds its own ceiling
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::BudgetCeilingExceeded
+        );
+        assert_eq!(BudgetProposalPallet::remaining_budget(0, AssetKind::Native), Some(0));
+        assert_eq!(BudgetProposalPallet::remaining_budget(0, AssetKind::WithId(STABLE)), Some(500));
+    });
+}
+
+// Test proposals record the beneficiary, paying wallet and schedule
+#[test]
+fn create_proposal_records_beneficiary_source_and_schedule() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            900,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            Some(WALLET),
+            PaymentSchedule::Periodic { installments: 3, interval: 10 }
+        ));
+
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.creator, 1);
+        assert_eq!(proposal.beneficiary, 5);
+        assert_eq!(proposal.source, Some(WALLET));
+        assert_eq!(proposal.schedule, PaymentSchedule::Periodic { installments: 3, interval: 10 });
+        assert_eq!(proposal.disbursed, 0);
+    });
+}
+
+// Test proposals cannot name an unknown government wallet
+#[test]
+fn create_proposal_fails_for_unknown_wallet() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                900,
+                b"Bridge construction".to_vec(),
+                0,
+                AssetKind::Native,
+                5,
+                Some(7),
+                PaymentSchedule::LumpSum
+            ),
+            Error::<Test>::UnknownWallet
+        );
+    });
+}
+
+// Test periodic schedules need installments and an interval
+#[test]
+fn create_proposal_fails_for_invalid_schedule() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                900,
+                b"Bridge construction".to_vec(),
+                0,
+                AssetKind::Native,
+                5,
+                None,
+                PaymentSchedule::Periodic { installments: 0, interval: 10 }
+            ),
+            Error::<Test>::InvalidSchedule
+        );
+        assert_noop!(
+            BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                900,
+                b"Bridge construction".to_vec(),
+                0,
+                AssetKind::Native,
+                5,
+                None,
+                PaymentSchedule::Periodic { installments: 3, interval: 0 }
+            ),
+            Error::<Test>::InvalidSchedule
+        );
+    });
+}
+
+// Test a lump-sum proposal is paid in full from the treasury
+#[test]
+fn execute_proposal_pays_lump_sum_from_treasury() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let treasury = BudgetProposalPallet::treasury_account();
+        fund(treasury, AssetKind::Native, 10_000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        System::set_block_number(11);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+
+        System::assert_has_event(
+            Event::ProposalPaymentExecuted {
+                proposal_id: 0,
+                source: treasury,
+                beneficiary: 5,
+                amount: 1000,
+            }
+            .into(),
+        );
+        System::assert_last_event(Event::BudgetProposalExecuted { proposal_id: 0 }.into());
+        assert_eq!(Balances::free_balance(5), 1000);
+        assert_eq!(Balances::free_balance(treasury), 9000);
+
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+        assert_eq!(proposal.disbursed, 1000);
+
+        // Nothing is left to pay
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ProposalNotApproved
+        );
+    });
+}
+
+// Test a government wallet can move funds into its payment account
+#[test]
+fn fund_wallet_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(WALLET, AssetKind::WithId(STABLE), 6000);
+
+        assert_ok!(BudgetProposalPallet::fund_wallet(
+            RuntimeOrigin::signed(WALLET),
+            AssetKind::WithId(STABLE),
+            5000
+        ));
+
+        let wallet_account = BudgetProposalPallet::wallet_account(&WALLET);
+        assert_eq!(Assets::balance(STABLE, WALLET), 1000);
+        assert_eq!(Assets::balance(STABLE, wallet_account), 5000);
+        System::assert_last_event(
+            Event::WalletFunded {
+                wallet: WALLET,
+                asset: AssetKind::WithId(STABLE),
+                amount: 5000,
+            }
+            .into(),
+        );
+    });
+}
+
+// Test only a government wallet with enough funds can fund a payment account
+#[test]
+fn fund_wallet_fails_for_invalid_funding() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(1, AssetKind::WithId(STABLE), 6000);
+        fund(WALLET, AssetKind::WithId(STABLE), 1000);
+
+        // Not a government wallet
+        assert_noop!(
+            BudgetProposalPallet::fund_wallet(
+                RuntimeOrigin::signed(1),
+                AssetKind::WithId(STABLE),
+                5000
+            ),
+            Error::<Test>::UnknownWallet
+        );
+
+        // More than the wallet holds
+        assert!(BudgetProposalPallet::fund_wallet(
+            RuntimeOrigin::signed(WALLET),
+            AssetKind::WithId(STABLE),
+            5000
+        )
+        .is_err());
+        assert_eq!(Assets::balance(STABLE, WALLET), 1000);
+    });
+}
+
+// Test a proposal naming a government wallet is paid from the funds the wallet moved into its
+// payment account
+#[test]
+fn execute_proposal_pays_from_government_wallet() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let wallet_account = BudgetProposalPallet::wallet_account(&WALLET);
+        fund(WALLET, AssetKind::WithId(STABLE), 6000);
+        assert_ok!(BudgetProposalPallet::fund_wallet(
+            RuntimeOrigin::signed(WALLET),
+            AssetKind::WithId(STABLE),
+            5000
+        ));
+        WalletLedger::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            2000,
+            b"Imported medicine".to_vec(),
+            0,
+            AssetKind::WithId(STABLE),
+            5,
+            Some(WALLET),
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        System::set_block_number(11);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+
+        assert_eq!(Assets::balance(STABLE, 5), 2000);
+        assert_eq!(Assets::balance(STABLE, wallet_account), 3000);
+        assert_eq!(Assets::balance(STABLE, WALLET), 1000);
+        assert_eq!(WalletLedger::get(), 3000);
+    });
+}
+
+// Test a wallet payment is not made beyond the wallet's ledger balance
+#[test]
+fn execute_proposal_fails_beyond_wallet_ledger() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::wallet_account(&WALLET), AssetKind::WithId(STABLE), 5000);
+        WalletLedger::set(1000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            2000,
+            b"Imported medicine".to_vec(),
+            0,
+            AssetKind::WithId(STABLE),
+            5,
+            Some(WALLET),
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        System::set_block_number(11);
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            DispatchError::Other("Insufficient balance")
+        );
+    });
+}
+
+// Test the wallet account itself is never debited for a proposal naming it
+#[test]
+fn execute_proposal_does_not_debit_wallet_account_itself() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(WALLET, AssetKind::WithId(STABLE), 5000);
+        WalletLedger::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            2000,
+            b"Imported medicine".to_vec(),
+            0,
+            AssetKind::WithId(STABLE),
+            5,
+            Some(WALLET),
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        System::set_block_number(11);
+        assert!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0).is_err());
+
+        assert_eq!(Assets::balance(STABLE, WALLET), 5000);
+        assert_eq!(Assets::balance(STABLE, 5), 0);
+    });
+}
+
+// Test a proposal cannot be executed before approval
+#[test]
+fn execute_proposal_fails_when_not_approved() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ProposalNotApproved
+        );
+    });
+}
+
+// Test a deferred proposal is paid only once its start block is reached
+#[test]
+fn execute_proposal_waits_for_start_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, 10_000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::FromBlock(50)
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        System::set_block_number(11);
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::PaymentNotDue
+        );
+
+        System::set_block_number(50);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+        assert_eq!(Balances::free_balance(5), 1000);
+    });
+}
+
+// Test a periodic proposal is paid in installments, the last settling any remainder
+#[test]
+fn execute_proposal_pays_periodic_installments() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, 10_000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Teacher salaries".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::Periodic { installments: 3, interval: 10 }
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        // First installment is due once the challenge period ends
+        System::set_block_number(11);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+        assert_eq!(Balances::free_balance(5), 333);
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::PaymentNotDue
+        );
+
+        // Missed installments are paid together
+        System::set_block_number(31);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+        assert_eq!(Balances::free_balance(5), 1000);
+
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.installments_paid, 3);
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+    });
+}
+
+// Test an approved proposal waits out its challenge period before execution
+#[test]
+fn approve_proposal_starts_challenge_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, 10_000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::AwaitingExecution);
+        assert_eq!(proposal.executable_at, Some(11));
+
+        // Execution is blocked until the window closes
+        System::set_block_number(10);
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ChallengePeriodActive
+        );
+
+        System::set_block_number(11);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Executed);
+    });
+}
+
+// Test the oversight origin can veto during the challenge period
+#[test]
+fn veto_proposal_works_during_challenge_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 1000);
+
+        System::set_block_number(5);
+        assert_ok!(BudgetProposalPallet::veto_proposal(
+            RuntimeOrigin::root(),
+            0,
+            b"Procurement irregularities".to_vec()
+        ));
+
+        System::assert_last_event(
+            Event::BudgetProposalVetoed {
+                proposal_id: 0,
+                reason: BoundedVec::try_from(b"Procurement irregularities".to_vec()).unwrap(),
+            }
+            .into(),
+        );
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Vetoed);
+
+        // The committed amount is released and the proposal can no longer be executed
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 0);
+        assert_eq!(CategoryBudgets::<Test>::get((0, 0, AssetKind::Native)).committed, 0);
+        System::set_block_number(11);
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ProposalNotApproved
+        );
+    });
+}
+
+// Test vetoes are restricted to the oversight origin and the challenge period
+#[test]
+fn veto_proposal_fails_outside_challenge_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Bridge construction".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Pending proposals cannot be vetoed
+        assert_noop!(
+            BudgetProposalPallet::veto_proposal(RuntimeOrigin::root(), 0, b"Too early".to_vec()),
+            Error::<Test>::ProposalNotAwaitingExecution
+        );
+
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        assert_noop!(
+            BudgetProposalPallet::veto_proposal(RuntimeOrigin::signed(2), 0, b"Nope".to_vec()),
+            sp_runtime::traits::BadOrigin
+        );
+
+        // The window has closed
+        System::set_block_number(11);
+        assert_noop!(
+            BudgetProposalPallet::veto_proposal(RuntimeOrigin::root(), 0, b"Too late".to_vec()),
+            Error::<Test>::ChallengePeriodOver
+        );
+    });
+}
+
+// Test proposals above the referendum threshold are put to a community vote
+#[test]
+fn large_proposal_opens_referendum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ReferendumThreshold::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            5001,
+            b"New hospital".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        System::assert_last_event(Event::BudgetProposalReferendumOpened { proposal_id: 0 }.into());
+        assert_eq!(OpenedReferenda::get(), vec![0]);
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::UnderReferendum);
+
+        // The referendum decides, not an approver, and the proposal does not expire
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotPending
+        );
+        run_to_block(101);
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::UnderReferendum);
+    });
+}
+
+// Test proposals at the threshold are still decided by an approver
+#[test]
+fn proposal_at_threshold_skips_referendum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ReferendumThreshold::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            5000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        assert!(OpenedReferenda::get().is_empty());
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Pending);
+    });
+}
+
+// Test raising a proposal above the threshold puts it to a community vote
+#[test]
+fn update_proposal_above_threshold_opens_referendum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ReferendumThreshold::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Road repairs".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::update_proposal(
+            RuntimeOrigin::signed(1),
+            0,
+            5001,
+            b"Road repairs".to_vec()
+        ));
+
+        System::assert_last_event(Event::BudgetProposalReferendumOpened { proposal_id: 0 }.into());
+        assert_eq!(OpenedReferenda::get(), vec![0]);
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::UnderReferendum);
+
+        // An approver can no longer decide it
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotPending
+        );
+    });
+}
+
+// Test a passed referendum approves the proposal
+#[test]
+fn passed_referendum_approves_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ReferendumThreshold::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            8000,
+            b"New hospital".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        System::set_block_number(20);
+        assert_ok!(BudgetProposalPallet::conclude_referendum(0, true));
+
+        System::assert_last_event(
+            Event::BudgetProposalReferendumConcluded { proposal_id: 0, approved: true }.into(),
+        );
+        let proposal = BudgetProposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::AwaitingExecution);
+        assert_eq!(proposal.executable_at, Some(30));
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 8000);
+
+        // The outcome is applied only once
+        assert_noop!(
+            BudgetProposalPallet::conclude_referendum(0, false),
+            Error::<Test>::ProposalNotUnderReferendum
+        );
+    });
+}
+
+// Test a failed referendum rejects the proposal
+#[test]
+fn failed_referendum_rejects_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ReferendumThreshold::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            8000,
+            b"New hospital".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::conclude_referendum(0, false));
+
+        System::assert_last_event(
+            Event::BudgetProposalReferendumConcluded { proposal_id: 0, approved: false }.into(),
+        );
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Rejected);
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 0);
+    });
+}
+
+// Test a passed referendum that no longer fits the budget rejects the proposal
+#[test]
+fn passed_referendum_over_ceiling_rejects_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ReferendumThreshold::set(5000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            8000,
+            b"New hospital".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::set_category_allocation(
+            RuntimeOrigin::root(),
+            0,
+            0,
+            AssetKind::Native,
+            6000
+        ));
+        assert_ok!(BudgetProposalPallet::conclude_referendum(0, true));
+
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Rejected);
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 0);
+        assert_eq!(CategoryBudgets::<Test>::get((0, 0, AssetKind::Native)).committed, 0);
+    });
+}
+
+// Test a recurring line is paid every interval until its end block
+#[test]
+fn recurring_line_pays_every_interval_until_end() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let treasury = BudgetProposalPallet::treasury_account();
+        fund(treasury, AssetKind::Native, 10_000);
+
+        assert_ok!(BudgetProposalPallet::create_recurring_line(
+            RuntimeOrigin::root(),
+            5,
+            AssetKind::Native,
+            100,
+            0,
+            10,
+            5,
+            25
+        ));
+        System::assert_last_event(
+            Event::RecurringLineCreated { line_id: 0, beneficiary: 5, amount: 100, interval: 10 }
+                .into(),
+        );
+        assert_eq!(RecurringPayments::<Test>::get(5).to_vec(), vec![0]);
+
+        run_to_block(5);
+        System::assert_has_event(
+            Event::RecurringPaymentMade { line_id: 0, beneficiary: 5, amount: 100 }.into(),
+        );
+        assert_eq!(Balances::free_balance(5), 100);
+        assert_eq!(RecurringLines::<Test>::get(0).unwrap().next_payment, 15);
+
+        // Payments at blocks 15 and 25, then the line ends
+        run_to_block(40);
+        assert_eq!(Balances::free_balance(5), 300);
+        assert_eq!(Balances::free_balance(treasury), 9700);
+        System::assert_has_event(Event::RecurringLineEnded { line_id: 0 }.into());
+
+        let line = RecurringLines::<Test>::get(0).unwrap();
+        assert_eq!(line.status, RecurringLineStatus::Ended);
+        assert_eq!(line.payments_made, 3);
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 300);
+    });
+}
+
+// Test recurring lines can only be created by the budget origin with a valid schedule
+#[test]
+fn create_recurring_line_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+
+        assert_noop!(
+            BudgetProposalPallet::create_recurring_line(
+                RuntimeOrigin::signed(1),
+                5,
+                AssetKind::Native,
+                100,
+                0,
+                10,
+                20,
+                100
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+        // First payment must be in the future
+        assert_noop!(
+            BudgetProposalPallet::create_recurring_line(
+                RuntimeOrigin::root(),
+                5,
+                AssetKind::Native,
+                100,
+                0,
+                10,
+                10,
+                100
+            ),
+            Error::<Test>::InvalidRecurringLine
+        );
+        // Zero interval
+        assert_noop!(
+            BudgetProposalPallet::create_recurring_line(
+                RuntimeOrigin::root(),
+                5,
+                AssetKind::Native,
+                100,
+                0,
+                0,
+                20,
+                100
+            ),
+            Error::<Test>::InvalidRecurringLine
+        );
+        // Ends before the first payment
+        assert_noop!(
+            BudgetProposalPallet::create_recurring_line(
+                RuntimeOrigin::root(),
+                5,
+                AssetKind::Native,
+                100,
+                0,
+                10,
+                20,
+                19
+            ),
+            Error::<Test>::InvalidRecurringLine
+        );
+        assert_noop!(
+            BudgetProposalPallet::create_recurring_line(
+                RuntimeOrigin::root(),
+                5,
+                AssetKind::Native,
+                100,
+                7,
+                10,
+                20,
+                100
+            ),
+            Error::<Test>::CategoryDoesNotExist
+        );
+    });
+}
+
+// Test a recurring line pauses when the treasury runs short and can be resumed
+#[test]
+fn recurring_line_pauses_on_insufficient_funds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let treasury = BudgetProposalPallet::treasury_account();
+        fund(treasury, AssetKind::Native, 150);
+
+        assert_ok!(BudgetProposalPallet::create_recurring_line(
+            RuntimeOrigin::root(),
+            5,
+            AssetKind::Native,
+            100,
+            0,
+            10,
+            5,
+            100
+        ));
+
+        // First payment succeeds, the second cannot be covered
+        run_to_block(15);
+        assert_eq!(Balances::free_balance(5), 100);
+        System::assert_has_event(
+            Event::RecurringPaymentFailed {
+                line_id: 0,
+                reason: RecurringPaymentFailure::InsufficientFunds,
+            }
+            .into(),
+        );
+        System::assert_last_event(Event::RecurringLinePaused { line_id: 0 }.into());
+
+        let line = RecurringLines::<Test>::get(0).unwrap();
+        assert_eq!(line.status, RecurringLineStatus::Paused);
+        assert_eq!(line.last_failure, Some((15, RecurringPaymentFailure::InsufficientFunds)));
+
+        // Nothing is paid while paused
+        run_to_block(30);
+        assert_eq!(Balances::free_balance(5), 100);
+
+        // Top up and resume: the next payment is made in the following block
+        fund(treasury, AssetKind::Native, 1000);
+        assert_ok!(BudgetProposalPallet::resume_recurring_line(RuntimeOrigin::root(), 0));
+        System::assert_last_event(
+            Event::RecurringLineResumed { line_id: 0, next_payment: 31 }.into(),
+        );
+        run_to_block(31);
+        assert_eq!(Balances::free_balance(5), 200);
+        assert_eq!(RecurringLines::<Test>::get(0).unwrap().next_payment, 41);
+
+        // Only paused lines can be resumed
+        assert_noop!(
+            BudgetProposalPallet::resume_recurring_line(RuntimeOrigin::root(), 0),
+            Error::<Test>::RecurringLineNotPaused
+        );
+    });
+}
+
+// Test a payment over the budget is recorded as failed without pausing the line
+#[test]
+fn recurring_payment_over_budget_is_recorded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, 10_000);
+        assert_ok!(BudgetProposalPallet::set_budget_ceiling(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            150
+        ));
+
+        assert_ok!(BudgetProposalPallet::create_recurring_line(
+            RuntimeOrigin::root(),
+            5,
+            AssetKind::Native,
+            100,
+            0,
+            10,
+            5,
+            100
+        ));
+
+        run_to_block(15);
+        assert_eq!(Balances::free_balance(5), 100);
+        System::assert_has_event(
+            Event::RecurringPaymentFailed {
+                line_id: 0,
+                reason: RecurringPaymentFailure::BudgetExceeded,
+            }
+            .into(),
+        );
+
+        let line = RecurringLines::<Test>::get(0).unwrap();
+        assert_eq!(line.status, RecurringLineStatus::Active);
+        assert_eq!(line.last_failure, Some((15, RecurringPaymentFailure::BudgetExceeded)));
+        assert_eq!(line.next_payment, 25);
+    });
+}
+
+// Test a cancelled recurring line makes no further payments
+#[test]
+fn cancel_recurring_line_stops_payments() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, 10_000);
+
+        assert_ok!(BudgetProposalPallet::create_recurring_line(
+            RuntimeOrigin::root(),
+            5,
+            AssetKind::Native,
+            100,
+            0,
+            10,
+            5,
+            100
+        ));
+        run_to_block(5);
+        assert_ok!(BudgetProposalPallet::cancel_recurring_line(RuntimeOrigin::root(), 0));
+        System::assert_last_event(Event::RecurringLineCancelled { line_id: 0 }.into());
+
+        run_to_block(40);
+        assert_eq!(Balances::free_balance(5), 100);
+        assert_eq!(RecurringLines::<Test>::get(0).unwrap().status, RecurringLineStatus::Cancelled);
+        assert_noop!(
+            BudgetProposalPallet::cancel_recurring_line(RuntimeOrigin::root(), 0),
+            Error::<Test>::RecurringLineFinished
+        );
+    });
+}
+
+// Create, approve and pay out a lump-sum proposal of `amount` to account 5 at block 11
+fn execute_lump_sum_proposal(amount: u128) -> u64 {
+    let proposal_id = crate::NextProposalId::<Test>::get();
+    fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, amount);
+    assert_ok!(BudgetProposalPallet::create_proposal(
+        RuntimeOrigin::signed(1),
+        amount,
+        b"School renovation".to_vec(),
+        0,
+        AssetKind::Native,
+        5,
+        None,
+        PaymentSchedule::LumpSum
+    ));
+    assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), proposal_id));
+    System::set_block_number(11);
+    assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), proposal_id));
+    proposal_id
+}
+
+fn expense(amount: u128, receipt: u8) -> ExpenseItem<u128> {
+    ExpenseItem {
+        amount,
+        receipt_hash: [receipt; 32],
+        description: BoundedVec::try_from(b"Building materials".to_vec()).unwrap(),
+    }
+}
+
+// Test the beneficiary can report expenses until the proposal is reconciled
+#[test]
+fn submit_expense_report_reconciles_executed_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal_id = execute_lump_sum_proposal(1000);
+
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(300, 1), expense(200, 2)]
+        ));
+        System::assert_last_event(
+            Event::ExpenseReportSubmitted { proposal_id, items: 2, amount: 500, reported: 500 }
+                .into(),
+        );
+        assert_eq!(BudgetProposalPallet::unreported_balance(proposal_id), Some(500));
+
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(500, 3)]
+        ));
+        System::assert_last_event(Event::ProposalReconciled { proposal_id }.into());
+        assert_eq!(ExpenseReports::<Test>::get(proposal_id).len(), 3);
+        assert_eq!(Reconciliations::<Test>::get(proposal_id).reported, 1000);
+        assert_eq!(BudgetProposalPallet::unreported_balance(proposal_id), Some(0));
+    });
+}
+
+// Test expense reports are validated
+#[test]
+fn submit_expense_report_fails_for_invalid_report() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Nothing was paid yet
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"School renovation".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::submit_expense_report(
+                RuntimeOrigin::signed(5),
+                0,
+                vec![expense(100, 1)]
+            ),
+            Error::<Test>::ProposalNotDisbursed
+        );
+
+        let proposal_id = execute_lump_sum_proposal(1000);
+        assert_noop!(
+            BudgetProposalPallet::submit_expense_report(
+                RuntimeOrigin::signed(1),
+                proposal_id,
+                vec![expense(100, 1)]
+            ),
+            Error::<Test>::NotProposalBeneficiary
+        );
+        assert_noop!(
+            BudgetProposalPallet::submit_expense_report(
+                RuntimeOrigin::signed(5),
+                proposal_id,
+                vec![]
+            ),
+            Error::<Test>::InvalidExpenseReport
+        );
+        assert_noop!(
+            BudgetProposalPallet::submit_expense_report(
+                RuntimeOrigin::signed(5),
+                proposal_id,
+                vec![expense(0, 1)]
+            ),
+            Error::<Test>::InvalidExpenseReport
+        );
+        assert_noop!(
+            BudgetProposalPallet::submit_expense_report(
+                RuntimeOrigin::signed(5),
+                proposal_id,
+                vec![expense(600, 1), expense(600, 2)]
+            ),
+            Error::<Test>::ExpensesExceedDisbursed
+        );
+        assert_noop!(
+            BudgetProposalPallet::submit_expense_report(
+                RuntimeOrigin::signed(5),
+                proposal_id,
+                (0..6).map(|i| expense(1, i)).collect()
+            ),
+            Error::<Test>::TooManyExpenseItems
+        );
+    });
+}
+
+// Test a proposal is flagged when its reporting deadline passes unreconciled
+#[test]
+fn reporting_deadline_flags_unreconciled_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal_id = execute_lump_sum_proposal(1000);
+        assert_eq!(Reconciliations::<Test>::get(proposal_id).deadline, Some(61));
+
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(400, 1)]
+        ));
+
+        run_to_block(61);
+        System::assert_last_event(
+            Event::ProposalUnreconciled { proposal_id, disbursed: 1000, reported: 400 }.into(),
+        );
+        assert!(Reconciliations::<Test>::get(proposal_id).flagged);
+
+        // A late report that accounts for the rest clears the flag
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(600, 2)]
+        ));
+        System::assert_last_event(Event::ProposalReconciled { proposal_id }.into());
+        assert!(!Reconciliations::<Test>::get(proposal_id).flagged);
+    });
+}
+
+// Test a proposal reported in full before its deadline is not flagged
+#[test]
+fn reporting_deadline_skips_reconciled_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal_id = execute_lump_sum_proposal(1000);
+
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(1000, 1)]
+        ));
+
+        run_to_block(61);
+        assert!(!Reconciliations::<Test>::get(proposal_id).flagged);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::BudgetProposalPallet(Event::ProposalUnreconciled { .. })
+        )));
+    });
+}
+
+// Test payouts are not limited by the reporting deadlines falling in one block, and the
+// deadlines beyond the per-block limit are checked in the next block
+#[test]
+fn reporting_deadlines_beyond_limit_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Mock checks three deadlines per block
+        let proposal_ids = (0..4).map(|_| execute_lump_sum_proposal(1000)).collect::<Vec<_>>();
+        assert_eq!(ReportingDeadlines::<Test>::iter_key_prefix(61).count(), 4);
+
+        run_to_block(61);
+        let flagged = proposal_ids
+            .iter()
+            .filter(|id| Reconciliations::<Test>::get(**id).flagged)
+            .count();
+        assert_eq!(flagged, 3);
+        assert_eq!(ReportingBacklog::<Test>::get(), Some(61));
+
+        // The leftover deadline is checked in the next block
+        run_to_block(62);
+        for proposal_id in proposal_ids {
+            assert!(Reconciliations::<Test>::get(proposal_id).flagged);
+        }
+        assert!(!ReportingDeadlines::<Test>::contains_prefix(61));
+        assert_eq!(ReportingBacklog::<Test>::get(), None);
+    });
+}
+
+// Test unspent funds can be clawed back in several parts
+#[test]
+fn claw_back_recovers_funds_in_parts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal_id = execute_lump_sum_proposal(1000);
+        let treasury = BudgetProposalPallet::treasury_account();
+
+        assert_ok!(BudgetProposalPallet::claw_back(
+            RuntimeOrigin::root(),
+            proposal_id,
+            300,
+            b"Inflated invoices".to_vec()
+        ));
+        System::assert_last_event(
+            Event::FundsClawedBack {
+                proposal_id,
+                beneficiary: 5,
+                amount: 300,
+                reason: BoundedVec::try_from(b"Inflated invoices".to_vec()).unwrap(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            BudgetProposals::<Test>::get(proposal_id).unwrap().status,
+            ProposalStatus::ClawedBack
+        );
+        assert_eq!(Balances::free_balance(5), 700);
+        assert_eq!(Balances::free_balance(treasury), 300);
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 700);
+        assert_eq!(BudgetProposalPallet::unreported_balance(proposal_id), Some(700));
+
+        // Part of the rest is accounted for, the remainder is recovered
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(400, 1)]
+        ));
+        assert_ok!(BudgetProposalPallet::claw_back(
+            RuntimeOrigin::root(),
+            proposal_id,
+            300,
+            b"Work not delivered".to_vec()
+        ));
+        System::assert_last_event(Event::ProposalReconciled { proposal_id }.into());
+
+        let clawbacks = Clawbacks::<Test>::get(proposal_id);
+        assert_eq!(clawbacks.len(), 2);
+        assert_eq!(clawbacks[1].amount, 300);
+        assert_eq!(clawbacks[1].reason.to_vec(), b"Work not delivered".to_vec());
+        assert_eq!(clawbacks[1].at, 11);
+        assert_eq!(Reconciliations::<Test>::get(proposal_id).clawed_back, 600);
+        assert_eq!(Balances::free_balance(treasury), 600);
+        assert_eq!(BudgetProposalPallet::unreported_balance(proposal_id), Some(0));
+    });
+}
+
+// Test clawing back a proposal that is still being paid out stops its payments
+#[test]
+fn claw_back_stops_periodic_payments() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        fund(BudgetProposalPallet::treasury_account(), AssetKind::Native, 1000);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Teacher salaries".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::Periodic { installments: 4, interval: 10 }
+        ));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+        System::set_block_number(11);
+        assert_ok!(BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0));
+        assert_eq!(Balances::free_balance(5), 250);
+
+        assert_ok!(BudgetProposalPallet::claw_back(
+            RuntimeOrigin::root(),
+            0,
+            250,
+            b"Ghost employees".to_vec()
+        ));
+
+        // The recovered and undisbursed amounts are released
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 0);
+        assert_eq!(CategoryBudgets::<Test>::get((0, 0, AssetKind::Native)).committed, 0);
+
+        // No further installments are paid
+        System::set_block_number(21);
+        assert_noop!(
+            BudgetProposalPallet::execute_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ProposalNotApproved
+        );
+        assert_eq!(Reconciliations::<Test>::get(0).deadline, Some(61));
+    });
+}
+
+// Test clawbacks are validated
+#[test]
+fn claw_back_fails_for_invalid_clawback() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"School renovation".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::claw_back(RuntimeOrigin::root(), 0, 100, b"Misuse".to_vec()),
+            Error::<Test>::ProposalNotClawable
+        );
+
+        let proposal_id = execute_lump_sum_proposal(1000);
+        assert_noop!(
+            BudgetProposalPallet::claw_back(
+                RuntimeOrigin::signed(1),
+                proposal_id,
+                100,
+                b"Misuse".to_vec()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            BudgetProposalPallet::claw_back(RuntimeOrigin::root(), proposal_id, 100, vec![]),
+            Error::<Test>::InvalidReason
+        );
+        assert_noop!(
+            BudgetProposalPallet::claw_back(
+                RuntimeOrigin::root(),
+                proposal_id,
+                0,
+                b"Misuse".to_vec()
+            ),
+            Error::<Test>::InvalidClawbackAmount
+        );
+
+        // Reported expenses cannot be clawed back
+        assert_ok!(BudgetProposalPallet::submit_expense_report(
+            RuntimeOrigin::signed(5),
+            proposal_id,
+            vec![expense(600, 1)]
+        ));
+        assert_noop!(
+            BudgetProposalPallet::claw_back(
+                RuntimeOrigin::root(),
+                proposal_id,
+                500,
+                b"Misuse".to_vec()
+            ),
+            Error::<Test>::InvalidClawbackAmount
+        );
+
+        for _ in 0..3 {
+            assert_ok!(BudgetProposalPallet::claw_back(
+                RuntimeOrigin::root(),
+                proposal_id,
+                10,
+                b"Misuse".to_vec()
+            ));
+        }
+        assert_noop!(
+            BudgetProposalPallet::claw_back(
+                RuntimeOrigin::root(),
+                proposal_id,
+                10,
+                b"Misuse".to_vec()
+            ),
+            Error::<Test>::TooManyClawbacks
+        );
+    });
+}
+
+// Create a pending lump-sum proposal of `amount` by `creator` and enter it into `round_id`
+fn enter_proposal(round_id: u32, creator: u64, amount: u128) -> u64 {
+    let proposal_id = crate::NextProposalId::<Test>::get();
+    assert_ok!(BudgetProposalPallet::create_proposal(
+        RuntimeOrigin::signed(creator),
+        amount,
+        b"Neighbourhood playground".to_vec(),
+        0,
+        AssetKind::Native,
+        creator,
+        None,
+        PaymentSchedule::LumpSum
+    ));
+    assert_ok!(BudgetProposalPallet::enter_round(
+        RuntimeOrigin::signed(creator),
+        round_id,
+        proposal_id
+    ));
+    proposal_id
+}
+
+// Test a round funds the highest-scoring proposals that fit in its pot
+#[test]
+fn close_round_funds_proposals_greedily() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Points(10),
+            20
+        ));
+        System::assert_last_event(
+            Event::RoundOpened { round_id: 0, pot: 1000, closes_at: 21 }.into(),
+        );
+
+        let library = enter_proposal(0, 1, 600);
+        let park = enter_proposal(0, 2, 500);
+        let clinic = enter_proposal(0, 3, 300);
+        let bench = enter_proposal(0, 4, 100);
+        assert_eq!(BudgetProposals::<Test>::get(park).unwrap().status, ProposalStatus::InRound);
+        assert_eq!(RoundProposals::<Test>::get(0).to_vec(), vec![library, park, clinic, bench]);
+
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(10),
+            0,
+            vec![(library, 5), (park, 5)]
+        ));
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(11),
+            0,
+            vec![(park, 6), (clinic, 4)]
+        ));
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(12),
+            0,
+            vec![(clinic, 10)]
+        ));
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(13),
+            0,
+            vec![(bench, 1)]
+        ));
+        System::assert_last_event(
+            Event::VotesAllocated { round_id: 0, voter: 13, proposals: 1 }.into(),
+        );
+        assert_eq!(RoundScores::<Test>::get(0, clinic), 14);
+
+        System::set_block_number(21);
+        assert_ok!(BudgetProposalPallet::close_round(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(
+            Event::RoundClosed { round_id: 0, funded: 3, spent: 900 }.into(),
+        );
+        System::assert_has_event(
+            Event::RoundProposalFunded { round_id: 0, proposal_id: clinic, score: 14 }.into(),
+        );
+
+        // The library no longer fits once the clinic and park are funded; the bench does
+        for (proposal_id, status) in [
+            (clinic, ProposalStatus::AwaitingExecution),
+            (park, ProposalStatus::AwaitingExecution),
+            (library, ProposalStatus::Rejected),
+            (bench, ProposalStatus::AwaitingExecution),
+        ] {
+            assert_eq!(BudgetProposals::<Test>::get(proposal_id).unwrap().status, status);
+        }
+        assert_eq!(FiscalBudgets::<Test>::get(0, AssetKind::Native).committed, 900);
+        assert!(Rounds::<Test>::get(0).unwrap().closed);
+    });
+}
+
+// Test a round does not fund proposals nobody voted for
+#[test]
+fn close_round_rejects_proposals_without_votes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Approval,
+            20
+        ));
+        let library = enter_proposal(0, 1, 600);
+        let park = enter_proposal(0, 2, 300);
+
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(10),
+            0,
+            vec![(library, 1)]
+        ));
+
+        System::set_block_number(21);
+        assert_ok!(BudgetProposalPallet::close_round(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(
+            Event::RoundClosed { round_id: 0, funded: 1, spent: 600 }.into(),
+        );
+        assert_eq!(
+            BudgetProposals::<Test>::get(library).unwrap().status,
+            ProposalStatus::AwaitingExecution
+        );
+        assert_eq!(BudgetProposals::<Test>::get(park).unwrap().status, ProposalStatus::Rejected);
+    });
+}
+
+// Test a new ballot replaces the earlier one of the same citizen
+#[test]
+fn allocate_votes_replaces_earlier_ballot() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Approval,
+            20
+        ));
+        let library = enter_proposal(0, 1, 600);
+        let park = enter_proposal(0, 2, 500);
+
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(10),
+            0,
+            vec![(library, 1), (park, 1)]
+        ));
+        assert_ok!(BudgetProposalPallet::allocate_votes(
+            RuntimeOrigin::signed(10),
+            0,
+            vec![(park, 1)]
+        ));
+
+        assert_eq!(RoundScores::<Test>::get(0, library), 0);
+        assert_eq!(RoundScores::<Test>::get(0, park), 1);
+        assert_eq!(RoundBallots::<Test>::get(0, 10).to_vec(), vec![(park, 1)]);
+    });
+}
+
+// Test rounds and round entries are validated
+#[test]
+fn round_fails_for_invalid_round_or_entry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for (pot, voting, duration) in [
+            (0, RoundVoting::Approval, 20),
+            (1000, RoundVoting::Points(0), 20),
+            (1000, RoundVoting::Approval, 0),
+        ] {
+            assert_noop!(
+                BudgetProposalPallet::open_round(
+                    RuntimeOrigin::root(),
+                    pot,
+                    AssetKind::Native,
+                    voting,
+                    duration
+                ),
+                Error::<Test>::InvalidRound
+            );
+        }
+        assert_noop!(
+            BudgetProposalPallet::open_round(
+                RuntimeOrigin::signed(1),
+                1000,
+                AssetKind::Native,
+                RoundVoting::Approval,
+                20
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Approval,
+            20
+        ));
+
+        // Only the creator may enter a pending proposal that fits the round
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1500,
+            b"Stadium".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            500,
+            b"Imported books".to_vec(),
+            0,
+            AssetKind::WithId(STABLE),
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::enter_round(RuntimeOrigin::signed(2), 0, 0),
+            Error::<Test>::NotProposalCreator
+        );
+        assert_noop!(
+            BudgetProposalPallet::enter_round(RuntimeOrigin::signed(1), 0, 0),
+            Error::<Test>::ProposalNotEligibleForRound
+        );
+        assert_noop!(
+            BudgetProposalPallet::enter_round(RuntimeOrigin::signed(1), 0, 1),
+            Error::<Test>::ProposalNotEligibleForRound
+        );
+        assert_noop!(
+            BudgetProposalPallet::enter_round(RuntimeOrigin::signed(1), 1, 1),
+            Error::<Test>::RoundDoesNotExist
+        );
+
+        for creator in 1..=5 {
+            enter_proposal(0, creator, 100);
+        }
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(6),
+            100,
+            b"Bus shelter".to_vec(),
+            0,
+            AssetKind::Native,
+            6,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::enter_round(RuntimeOrigin::signed(6), 0, 7),
+            Error::<Test>::TooManyRoundProposals
+        );
+
+        System::set_block_number(21);
+        assert_noop!(
+            BudgetProposalPallet::enter_round(RuntimeOrigin::signed(6), 0, 7),
+            Error::<Test>::RoundNotOpen
+        );
+    });
+}
+
+// Test ballots are validated
+#[test]
+fn allocate_votes_fails_for_invalid_ballot() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Points(10),
+            20
+        ));
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Approval,
+            20
+        ));
+        let library = enter_proposal(0, 1, 600);
+        let park = enter_proposal(1, 2, 500);
+
+        assert_noop!(
+            BudgetProposalPallet::allocate_votes(
+                RuntimeOrigin::signed(NON_CITIZEN),
+                0,
+                vec![(library, 1)]
+            ),
+            Error::<Test>::NotEligibleCitizen
+        );
+        for ballot in [
+            vec![],
+            vec![(library, 0)],
+            vec![(library, 5), (library, 5)],
+            vec![(park, 1)],
+            vec![(library, 11)],
+            vec![(library, 1); 6],
+        ] {
+            assert_noop!(
+                BudgetProposalPallet::allocate_votes(RuntimeOrigin::signed(10), 0, ballot),
+                Error::<Test>::InvalidBallot
+            );
+        }
+        assert_noop!(
+            BudgetProposalPallet::allocate_votes(RuntimeOrigin::signed(10), 1, vec![(park, 2)]),
+            Error::<Test>::InvalidBallot
+        );
+
+        System::set_block_number(21);
+        assert_noop!(
+            BudgetProposalPallet::allocate_votes(
+                RuntimeOrigin::signed(10),
+                0,
+                vec![(library, 1)]
+            ),
+            Error::<Test>::RoundNotOpen
+        );
+    });
+}
+
+// Test a round can only be closed once, after voting ends
+#[test]
+fn close_round_fails_when_open_or_closed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            BudgetProposalPallet::close_round(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::RoundDoesNotExist
+        );
+        assert_ok!(BudgetProposalPallet::open_round(
+            RuntimeOrigin::root(),
+            1000,
+            AssetKind::Native,
+            RoundVoting::Approval,
+            20
+        ));
+        assert_noop!(
+            BudgetProposalPallet::close_round(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::RoundStillOpen
+        );
+
+        System::set_block_number(21);
+        assert_ok!(BudgetProposalPallet::close_round(RuntimeOrigin::signed(1), 0));
+        System::assert_last_event(Event::RoundClosed { round_id: 0, funded: 0, spent: 0 }.into());
+        assert_noop!(
+            BudgetProposalPallet::close_round(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::RoundAlreadyClosed
+        );
+    });
+}
+
+// Test raising a proposal beyond its endorsements sends it back to seeking endorsements
+#[test]
+fn update_proposal_beyond_endorsements_seeks_endorsements() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        BaseEndorsements::set(1);
+        EndorsementStep::set(1000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            500,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 0));
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Pending);
+
+        // 2500 needs three endorsements
+        assert_ok!(BudgetProposalPallet::update_proposal(
+            RuntimeOrigin::signed(1),
+            0,
+            2500,
+            b"Community library".to_vec()
+        ));
+        assert_eq!(
+            BudgetProposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::SeekingEndorsements
+        );
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotPending
+        );
+
+        // Earlier endorsements still count
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(3), 0));
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(4), 0));
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Pending);
+    });
+}
+
+// Test a proposal is only reviewed once it has the endorsements its amount requires
+#[test]
+fn endorse_proposal_sends_proposal_for_review() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        BaseEndorsements::set(1);
+        EndorsementStep::set(1000);
+        assert_eq!(BudgetProposalPallet::required_endorsements(2500), 3);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            2500,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_eq!(
+            BudgetProposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::SeekingEndorsements
+        );
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotPending
+        );
+
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 0));
+        System::assert_last_event(
+            Event::ProposalEndorsed { proposal_id: 0, endorser: 2, endorsements: 1, required: 3 }
+                .into(),
+        );
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(3), 0));
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(4), 0));
+        System::assert_last_event(
+            Event::ProposalEndorsementsReached {
+                proposal_id: 0,
+                endorsers: BoundedVec::try_from(vec![2, 3, 4]).unwrap(),
+            }
+            .into(),
+        );
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Pending);
+        assert_eq!(Endorsements::<Test>::get(0).to_vec(), vec![2, 3, 4]);
+
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0));
+    });
+}
+
+// Test an endorsed proposal above the referendum threshold goes to a referendum
+#[test]
+fn endorse_proposal_opens_referendum_for_large_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        BaseEndorsements::set(1);
+        ReferendumThreshold::set(2000);
+
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            5000,
+            b"New hospital wing".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert!(OpenedReferenda::get().is_empty());
+
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 0));
+        System::assert_last_event(Event::BudgetProposalReferendumOpened { proposal_id: 0 }.into());
+        assert_eq!(
+            BudgetProposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::UnderReferendum
+        );
+        assert_eq!(OpenedReferenda::get(), vec![0]);
+    });
+}
+
+// Test endorsements are validated and capped
+#[test]
+fn endorse_proposal_fails_for_invalid_endorsement() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalDoesNotExist
+        );
+
+        // Without endorsements configured proposals go straight to review
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ProposalNotSeekingEndorsements
+        );
+
+        BaseEndorsements::set(2);
+        EndorsementStep::set(1);
+        assert_eq!(BudgetProposalPallet::required_endorsements(1000), 5);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+        assert_noop!(
+            BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::CannotEndorseOwnProposal
+        );
+        assert_ok!(BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 1));
+        assert_noop!(
+            BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::AlreadyEndorsed
+        );
+
+        // A full list of endorsers takes no more endorsements
+        Endorsements::<Test>::mutate(1, |endorsers| {
+            for endorser in 3..=6 {
+                endorsers.try_push(endorser).unwrap();
+            }
+        });
+        assert_noop!(
+            BudgetProposalPallet::endorse_proposal(RuntimeOrigin::signed(7), 1),
+            Error::<Test>::TooManyEndorsements
+        );
+    });
+}
+
+// Test a proposal seeking endorsements can be withdrawn and expires like a pending one
+#[test]
+fn proposal_seeking_endorsements_can_be_withdrawn_or_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        BaseEndorsements::set(1);
+
+        for _ in 0..2 {
+            assert_ok!(BudgetProposalPallet::create_proposal(
+                RuntimeOrigin::signed(1),
+                1000,
+                b"Community library".to_vec(),
+                0,
+                AssetKind::Native,
+                1,
+                None,
+                PaymentSchedule::LumpSum
+            ));
+        }
+        assert_ok!(BudgetProposalPallet::withdraw_proposal(RuntimeOrigin::signed(1), 0));
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Withdrawn);
+
+        run_to_block(101);
+        assert_eq!(BudgetProposals::<Test>::get(1).unwrap().status, ProposalStatus::Expired);
+    });
+}
+
+// Test an approver cannot approve proposals of accounts they declared a conflict with
+#[test]
+fn approve_proposal_fails_for_conflicted_approver() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        // Conflict with the creator, declared by the approver
+        assert_ok!(BudgetProposalPallet::declare_conflict(
+            RuntimeOrigin::signed(2),
+            1,
+            Relationship::Family
+        ));
+        System::assert_last_event(
+            Event::ConflictDeclared {
+                approver: 2,
+                related: 1,
+                relationship: Relationship::Family,
+                declared_by: DeclarationSource::Approver,
+            }
+            .into(),
+        );
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ConflictOfInterest
+        );
+
+        // Conflict with the beneficiary, declared by the ethics origin
+        assert_ok!(BudgetProposalPallet::set_conflict(
+            RuntimeOrigin::root(),
+            3,
+            5,
+            Relationship::Business
+        ));
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ConflictOfInterest
+        );
+        assert_eq!(
+            BudgetProposalPallet::conflict_declarations(&3),
+            vec![(
+                5,
+                ConflictDeclaration {
+                    relationship: Relationship::Business,
+                    declared_by: DeclarationSource::Ethics,
+                    declared_at: 1,
+                }
+            )]
+        );
+
+        // Once the declaration is removed the approver may decide again
+        assert_ok!(BudgetProposalPallet::remove_conflict(RuntimeOrigin::root(), 3, 5));
+        System::assert_last_event(Event::ConflictRemoved { approver: 3, related: 5 }.into());
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(3), 0));
+    });
+}
+
+// Test conflict declarations are validated
+#[test]
+fn declare_conflict_fails_for_invalid_declaration() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            BudgetProposalPallet::declare_conflict(
+                RuntimeOrigin::signed(2),
+                2,
+                Relationship::Other
+            ),
+            Error::<Test>::InvalidConflict
+        );
+        assert_ok!(BudgetProposalPallet::declare_conflict(
+            RuntimeOrigin::signed(2),
+            1,
+            Relationship::Family
+        ));
+        assert_noop!(
+            BudgetProposalPallet::declare_conflict(
+                RuntimeOrigin::signed(2),
+                1,
+                Relationship::Employment
+            ),
+            Error::<Test>::ConflictAlreadyDeclared
+        );
+
+        // Only the ethics origin may override or remove a declaration
+        assert_noop!(
+            BudgetProposalPallet::set_conflict(
+                RuntimeOrigin::signed(2),
+                2,
+                1,
+                Relationship::Other
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            BudgetProposalPallet::remove_conflict(RuntimeOrigin::signed(2), 2, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            BudgetProposalPallet::remove_conflict(RuntimeOrigin::root(), 2, 3),
+            Error::<Test>::ConflictDoesNotExist
+        );
+        assert_ok!(BudgetProposalPallet::set_conflict(
+            RuntimeOrigin::root(),
+            2,
+            1,
+            Relationship::Employment
+        ));
+        assert_eq!(
+            ConflictDeclarations::<Test>::get(2, 1).unwrap().relationship,
+            Relationship::Employment
+        );
+    });
+}
+
+// Test conflicted or recused approvers cannot reject the proposal either
+#[test]
+fn reject_proposal_fails_for_conflicted_or_recused_approver() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            5,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        assert_ok!(BudgetProposalPallet::declare_conflict(
+            RuntimeOrigin::signed(2),
+            5,
+            Relationship::Family
+        ));
+        assert_noop!(
+            BudgetProposalPallet::reject_proposal(
+                RuntimeOrigin::signed(2),
+                0,
+                b"Not needed".to_vec()
+            ),
+            Error::<Test>::ConflictOfInterest
+        );
+
+        assert_ok!(BudgetProposalPallet::recuse(RuntimeOrigin::signed(3), 0));
+        assert_noop!(
+            BudgetProposalPallet::reject_proposal(
+                RuntimeOrigin::signed(3),
+                0,
+                b"Not needed".to_vec()
+            ),
+            Error::<Test>::RecusedFromProposal
+        );
+
+        assert_ok!(BudgetProposalPallet::reject_proposal(
+            RuntimeOrigin::signed(4),
+            0,
+            b"Not needed".to_vec()
+        ));
+        assert_eq!(BudgetProposals::<Test>::get(0).unwrap().status, ProposalStatus::Rejected);
+    });
+}
+
+// Test a recused approver can no longer approve the proposal
+#[test]
+fn recuse_records_recusal_and_blocks_approval() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::create_proposal(
+            RuntimeOrigin::signed(1),
+            1000,
+            b"Community library".to_vec(),
+            0,
+            AssetKind::Native,
+            1,
+            None,
+            PaymentSchedule::LumpSum
+        ));
+
+        assert_ok!(BudgetProposalPallet::recuse(RuntimeOrigin::signed(2), 0));
+        System::assert_last_event(Event::ApproverRecused { proposal_id: 0, approver: 2 }.into());
+        assert_eq!(Recusals::<Test>::get(0, 2), Some(1));
+        assert_eq!(BudgetProposalPallet::recusals(0), vec![2]);
+
+        assert_noop!(
+            BudgetProposalPallet::recuse(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::AlreadyRecused
+        );
+        assert_noop!(
+            BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::RecusedFromProposal
+        );
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(3), 0));
+
+        // Decided proposals cannot be recused from
+        assert_noop!(
+            BudgetProposalPallet::recuse(RuntimeOrigin::signed(4), 0),
+            Error::<Test>::ProposalNotPending
+        );
+        assert_noop!(
+            BudgetProposalPallet::recuse(RuntimeOrigin::signed(4), 1),
+            Error::<Test>::ProposalDoesNotExist
+        );
+    });
+}
+
+// Create a lump sum proposal paying its creator in category `category`
+fn propose(creator: u64, amount: u128, category: u32, asset: AssetKind) {
+    assert_ok!(BudgetProposalPallet::create_proposal(
+        RuntimeOrigin::signed(creator),
+        amount,
+        b"Community project".to_vec(),
+        category,
+        asset,
+        creator,
+        None,
+        PaymentSchedule::LumpSum
+    ));
+}
+
+// Test proposals can be listed by status, creator, category and amount range
+#[test]
+fn proposals_are_filtered_and_paginated() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(BudgetProposalPallet::register_category(
+            RuntimeOrigin::root(),
+            b"Health".to_vec()
+        ));
+        propose(1, 100, 0, AssetKind::Native);
+        propose(2, 200, 1, AssetKind::Native);
+        propose(1, 300, 1, AssetKind::Native);
+        propose(2, 400, 0, AssetKind::Native);
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(3), 1));
+
+        let ids = |filter: ProposalFilter<u64, u128>, start, limit| {
+            BudgetProposalPallet::proposals(filter, start, limit)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+
+        let pending =
+            ProposalFilter { status: Some(ProposalStatus::Pending), ..Default::default() };
+        let by_creator = ProposalFilter { creator: Some(1), ..Default::default() };
+        let by_category = ProposalFilter { category: Some(1), ..Default::default() };
+        let by_amount =
+            ProposalFilter { min_amount: Some(200), max_amount: Some(300), ..Default::default() };
+        assert_eq!(ids(ProposalFilter::default(), 0, 10), vec![0, 1, 2, 3]);
+        assert_eq!(ids(pending, 0, 10), vec![0, 2, 3]);
+        assert_eq!(ids(by_creator, 0, 10), vec![0, 2]);
+        assert_eq!(ids(by_category, 0, 10), vec![1, 2]);
+        assert_eq!(ids(by_amount, 0, 10), vec![1, 2]);
+        assert_eq!(
+            ids(
+                ProposalFilter {
+                    status: Some(ProposalStatus::Pending),
+                    creator: Some(2),
+                    category: Some(0),
+                    ..Default::default()
+                },
+                0,
+                10
+            ),
+            vec![3]
+        );
+
+        // Pages start at the given ID and hold at most `limit` matches
+        assert_eq!(ids(ProposalFilter::default(), 0, 2), vec![0, 1]);
+        assert_eq!(ids(ProposalFilter::default(), 2, 2), vec![2, 3]);
+        assert_eq!(ids(ProposalFilter::default(), 4, 2), Vec::<u64>::new());
+        assert_eq!(ids(ProposalFilter { creator: Some(2), ..Default::default() }, 2, 1), vec![3]);
+    });
+}
+
+// Test proposal totals are grouped by outcome and kept per asset
+#[test]
+fn proposal_totals_are_grouped_by_outcome() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        propose(1, 100, 0, AssetKind::Native);
+        propose(1, 200, 0, AssetKind::Native);
+        propose(1, 400, 0, AssetKind::Native);
+        propose(1, 800, 0, AssetKind::Native);
+        propose(1, 50, 0, AssetKind::WithId(STABLE));
+        assert_ok!(BudgetProposalPallet::approve_proposal(RuntimeOrigin::signed(2), 1));
+        assert_ok!(BudgetProposalPallet::reject_proposal(
+            RuntimeOrigin::signed(2),
+            2,
+            b"Out of scope".to_vec()
+        ));
+        assert_ok!(BudgetProposalPallet::withdraw_proposal(RuntimeOrigin::signed(1), 3));
+
+        // Withdrawn proposals are not counted
+        assert_eq!(
+            BudgetProposalPallet::proposal_totals(AssetKind::Native),
+            ProposalTotals { pending: 100, approved: 200, rejected: 400 }
+        );
+        assert_eq!(
+            BudgetProposalPallet::proposal_totals(AssetKind::WithId(STABLE)),
+            ProposalTotals { pending: 50, approved: 0, rejected: 0 }
+        );
+        assert_eq!(
+            BudgetProposalPallet::proposal_totals(AssetKind::WithId(STABLE + 1)),
+            ProposalTotals::default()
+        );
+
+        // Clawed back proposals were approved and paid
+        BudgetProposals::<Test>::mutate(1, |proposal| {
+            proposal.as_mut().unwrap().status = ProposalStatus::ClawedBack
+        });
+        assert_eq!(BudgetProposalPallet::proposal_totals(AssetKind::Native).approved, 200);
+    });
+}
+
+// Test the version 1 migration drops version 0 proposals and keeps the next proposal ID
+#[test]
+fn migrate_v0_to_v1_resets_proposals() {
+    use frame::deps::frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade},
+    };
+
+    new_test_ext().execute_with(|| {
+        // A version 0 proposal: creator, amount, purpose, status, created_at, updated_at
+        let v0_proposal = (1u64, 500u128, b"Road repairs".to_vec(), 0u8, 1u64, 1u64);
+        unhashed::put(&BudgetProposals::<Test>::hashed_key_for(7), &v0_proposal);
+        NextProposalId::<Test>::put(8);
+        StorageVersion::new(0).put::<BudgetProposalPallet>();
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(BudgetProposals::<Test>::iter_keys().count(), 0);
+        assert_eq!(NextProposalId::<Test>::get(), 8);
+        assert_eq!(BudgetProposalPallet::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
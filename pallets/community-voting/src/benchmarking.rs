@@ -2,28 +2,77 @@
 
 use super::{Pallet as CommunityVotingPallet, *};
 use frame::deps::frame_support::assert_ok;
-use frame::{deps::frame_benchmarking::v2::*, prelude::*};
+use frame::{
+    deps::frame_benchmarking::v2::*,
+    prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate},
+        Zero,
+    },
+};
+use frame_system::RawOrigin;
+
+// Fund `voter` and cast a staked Yes vote on a new stake-weighted voting period lasting
+// `duration_blocks`. Returns the stake.
+fn staked_vote<T: Config>(voter: &T::AccountId, duration_blocks: u64) -> BalanceOf<T> {
+    let stake = T::Currency::minimum_balance() * 100u32.into();
+    T::Currency::set_balance(voter, stake * 2u32.into());
+    let proposal_id = T::BenchmarkHelper::proposal();
+    assert_ok!(CommunityVotingPallet::<T>::start_voting(
+        voting_origin::<T>(),
+        proposal_id,
+        duration_blocks,
+        VoteWeighting::Stake,
+        ThresholdPolicy::default(),
+        Secrecy::Public
+    ));
+    assert_ok!(CommunityVotingPallet::<T>::cast_staked_vote(
+        RawOrigin::Signed(voter.clone()).into(),
+        proposal_id,
+        0,
+        stake,
+        Conviction::None
+    ));
+    stake
+}
+
+// An origin allowed to put proposals to a vote
+fn voting_origin<T: Config>() -> T::RuntimeOrigin {
+    T::VotingOrigin::try_successful_origin().expect("voting origin exists")
+}
+
+// Register `who` in the voter registry, expiring at `expires_at`
+fn register<T: Config>(who: &T::AccountId, expires_at: Option<u64>) {
+    Citizens::<T>::insert(
+        who,
+        Registration { attributes: CitizenAttributes::default(), registered_at: 0, expires_at },
+    );
+    CitizenCount::<T>::mutate(|count| *count += 1);
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
     #[cfg(test)]
     use crate::pallet::Pallet as CommunityVotingPallet;
-    use frame_system::RawOrigin;
 
     #[benchmark]
-    fn start_voting() {
-        let caller: T::AccountId = whitelisted_caller();
-        
+    fn start_voting() -> Result<(), BenchmarkError> {
+        let origin =
+            T::VotingOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
         // Setup: Prepare voting parameters
-        let proposal_id = 1u64;
+        let proposal_id = T::BenchmarkHelper::proposal();
         let duration_blocks = 10u64;
         
         #[extrinsic_call]
         start_voting(
-            RawOrigin::Signed(caller.clone()), 
-            proposal_id, 
-            duration_blocks
+            origin as T::RuntimeOrigin,
+            proposal_id,
+            duration_blocks,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         );
 
         // Verification: Check that voting period was created and vote tally initialized
@@ -34,21 +83,25 @@ mod benchmarks {
         assert_eq!(vote_tally.yes_votes, 0);
         assert_eq!(vote_tally.no_votes, 0);
         assert_eq!(vote_tally.abstain_votes, 0);
+        Ok(())
     }
     #[benchmark]
     fn cast_vote() {
-        let creator: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = whitelisted_caller(); // Different account
         
         // Setup: First start a voting period
-        let proposal_id = 1u64;
+        let proposal_id = T::BenchmarkHelper::proposal();
         let duration_blocks = 10u64;
         
         assert_ok!(CommunityVotingPallet::<T>::start_voting(
-            RawOrigin::Signed(creator.clone()).into(),
+            voting_origin::<T>(),
             proposal_id,
-            duration_blocks
+            duration_blocks,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
+        register::<T>(&voter, None);
 
         // Cast a Yes vote (0)
         #[extrinsic_call]
@@ -63,17 +116,19 @@ mod benchmarks {
     }
         #[benchmark]
     fn end_voting() {
-        let creator: T::AccountId = whitelisted_caller();
         let ender: T::AccountId = whitelisted_caller(); // Different account
         
         // Setup: Start voting period with VERY SHORT duration that has already ended
-        let proposal_id = 1u64;
+        let proposal_id = T::BenchmarkHelper::proposal();
         let duration_blocks = 0u64; // Voting ends immediately
         
         assert_ok!(CommunityVotingPallet::<T>::start_voting(
-            RawOrigin::Signed(creator.clone()).into(),
+            voting_origin::<T>(),
             proposal_id,
-            duration_blocks
+            duration_blocks,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
 
         // Don't cast any votes - we just want to test the end_voting function
@@ -82,23 +137,26 @@ mod benchmarks {
         #[extrinsic_call]
         end_voting(RawOrigin::Signed(ender.clone()), proposal_id);
 
-        // Verification: Function should succeed 
-        // The voting period was set to end immediately, so end_voting should work
+        // Verification: Result should be stored as NoQuorum
+        assert_eq!(VotingResults::<T>::get(proposal_id), Some(VoteResult::NoQuorum));
     }
         #[benchmark]
     fn cast_vote_no() {
-        let creator: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = whitelisted_caller(); // Different account
         
         // Setup: First start a voting period
-        let proposal_id = 2u64; // Different proposal ID
+        let proposal_id = T::BenchmarkHelper::proposal();
         let duration_blocks = 10u64;
         
         assert_ok!(CommunityVotingPallet::<T>::start_voting(
-            RawOrigin::Signed(creator.clone()).into(),
+            voting_origin::<T>(),
             proposal_id,
-            duration_blocks
+            duration_blocks,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
+        register::<T>(&voter, None);
 
         // Cast a No vote (1)
         #[extrinsic_call]
@@ -111,6 +169,403 @@ mod benchmarks {
         assert_eq!(vote_tally.no_votes, 1);
         assert_eq!(vote_tally.abstain_votes, 0);
     }
+    #[benchmark]
+    fn close_voting_periods(n: Linear<0, { T::MaxClosuresPerBlock::get() }>) {
+        let duration_blocks = 10u64;
+
+        // Setup: Start `n` voting periods that all close in the same block
+        for _ in 0..n {
+            assert_ok!(CommunityVotingPallet::<T>::start_voting(
+                voting_origin::<T>(),
+                T::BenchmarkHelper::proposal(),
+                duration_blocks,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ));
+        }
+        let now: u64 = frame_system::Pallet::<T>::block_number()
+            .try_into()
+            .ok()
+            .expect("block number fits in u64");
+        let close_at = now + duration_blocks + 1;
+        let block: BlockNumberFor<T> = close_at.try_into().ok().expect("block number fits");
+
+        #[block]
+        {
+            CommunityVotingPallet::<T>::on_initialize(block);
+        }
+
+        // Verification: Check that the queue was drained
+        assert!(!VotingCloses::<T>::contains_prefix(close_at));
+    }
+    #[benchmark]
+    fn cast_staked_vote() {
+        let voter: T::AccountId = whitelisted_caller();
+
+        // Setup: The voter already has all other staked votes frozen
+        for _ in 1..T::MaxStakedVotes::get() {
+            staked_vote::<T>(&voter, 10);
+        }
+        let stake = T::Currency::minimum_balance() * 100u32.into();
+        let proposal_id = T::BenchmarkHelper::proposal();
+        assert_ok!(CommunityVotingPallet::<T>::start_voting(
+            voting_origin::<T>(),
+            proposal_id,
+            10,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+
+        #[extrinsic_call]
+        cast_staked_vote(
+            RawOrigin::Signed(voter.clone()),
+            proposal_id,
+            0,
+            stake,
+            Conviction::Locked6x
+        );
+
+        // Verification: Check that the stake counts towards the tally and is frozen
+        assert_eq!(VoteCounts::<T>::get(proposal_id).yes_stake, stake);
+        assert_eq!(VoterStakes::<T>::get(&voter).len() as u32, T::MaxStakedVotes::get());
+    }
+    #[benchmark]
+    fn unlock() {
+        let voter: T::AccountId = whitelisted_caller();
+
+        // Setup: Every staked vote of the voter is in an ended voting period
+        for _ in 0..T::MaxStakedVotes::get() {
+            staked_vote::<T>(&voter, 0);
+        }
+        let next = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(next);
+
+        #[extrinsic_call]
+        unlock(RawOrigin::Signed(voter.clone()), voter.clone());
+
+        // Verification: Check that nothing is frozen anymore
+        assert!(VoterStakes::<T>::get(&voter).is_empty());
+    }
+    #[benchmark]
+    fn register_citizen() -> Result<(), BenchmarkError> {
+        let origin =
+            T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let citizen: T::AccountId = account("citizen", 0, 0);
+        let attributes = CitizenAttributes { district: Some(1), age_band: Some(AgeBand::Adult) };
+        let registered = CitizenCount::<T>::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, citizen.clone(), attributes, Some(u64::MAX));
+
+        // Verification: Check that the citizen is registered
+        assert!(CommunityVotingPallet::<T>::is_registered(&citizen));
+        assert_eq!(CitizenCount::<T>::get(), registered + 1);
+        Ok(())
+    }
+    #[benchmark]
+    fn revoke_citizen() -> Result<(), BenchmarkError> {
+        let origin =
+            T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let citizen: T::AccountId = account("citizen", 0, 0);
+        register::<T>(&citizen, None);
+        let registered = CitizenCount::<T>::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, citizen.clone());
+
+        // Verification: Check that the registration was removed
+        assert!(!Citizens::<T>::contains_key(&citizen));
+        assert_eq!(CitizenCount::<T>::get(), registered - 1);
+        Ok(())
+    }
+    #[benchmark]
+    fn remove_expired_registration() {
+        let caller: T::AccountId = whitelisted_caller();
+        let citizen: T::AccountId = account("citizen", 0, 0);
+        register::<T>(&citizen, Some(0));
+        let registered = CitizenCount::<T>::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), citizen.clone());
+
+        // Verification: Check that the registration was removed
+        assert!(!Citizens::<T>::contains_key(&citizen));
+        assert_eq!(CitizenCount::<T>::get(), registered - 1);
+    }
+    #[benchmark]
+    fn delegate() {
+        let delegator: T::AccountId = whitelisted_caller();
+        let previous: T::AccountId = account("delegate", 0, 0);
+        let delegate: T::AccountId = account("delegate", 1, 0);
+        register::<T>(&delegator, None);
+
+        // Setup: The delegator replaces an earlier delegate, and the new delegate has all but
+        // one of its delegators
+        assert_ok!(CommunityVotingPallet::<T>::delegate(
+            RawOrigin::Signed(delegator.clone()).into(),
+            previous.clone(),
+            Some(1)
+        ));
+        for i in 1..T::MaxDelegators::get() {
+            let other: T::AccountId = account("delegator", i, 0);
+            register::<T>(&other, None);
+            assert_ok!(CommunityVotingPallet::<T>::delegate(
+                RawOrigin::Signed(other).into(),
+                delegate.clone(),
+                Some(1)
+            ));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(delegator.clone()), delegate.clone(), Some(1));
+
+        // Verification: Check that the delegation moved to the new delegate
+        assert_eq!(Delegations::<T>::get(&delegator, Some(1u32)), Some(delegate.clone()));
+        assert!(Delegators::<T>::get(&previous).is_empty());
+        assert_eq!(Delegators::<T>::get(&delegate).len() as u32, T::MaxDelegators::get());
+    }
+    #[benchmark]
+    fn undelegate() {
+        let delegator: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        register::<T>(&delegator, None);
+        assert_ok!(CommunityVotingPallet::<T>::delegate(
+            RawOrigin::Signed(delegator.clone()).into(),
+            delegate.clone(),
+            None
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(delegator.clone()), None);
+
+        // Verification: Check that the delegation was removed
+        assert!(Delegations::<T>::get(&delegator, None::<u32>).is_none());
+        assert!(Delegators::<T>::get(&delegate).is_empty());
+    }
+    #[benchmark]
+    fn change_vote() {
+        let voter: T::AccountId = whitelisted_caller();
+
+        // Setup: The voter has all staked votes frozen and changes the last one
+        for _ in 0..T::MaxStakedVotes::get() {
+            staked_vote::<T>(&voter, 10);
+        }
+        let proposal_id = VoterStakes::<T>::get(&voter).last().expect("vote was cast").proposal_id;
+        let stake = VoteCounts::<T>::get(proposal_id).yes_stake;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter.clone()), proposal_id, 1);
+
+        // Verification: Check that the weight moved to the new choice
+        let vote_tally = VoteCounts::<T>::get(proposal_id);
+        assert!(vote_tally.yes_stake.is_zero());
+        assert_eq!(vote_tally.no_stake, stake);
+    }
+    #[benchmark]
+    fn remove_vote() {
+        let voter: T::AccountId = whitelisted_caller();
+
+        // Setup: The voter has all staked votes frozen and retracts the last one
+        for _ in 0..T::MaxStakedVotes::get() {
+            staked_vote::<T>(&voter, 10);
+        }
+        let proposal_id = VoterStakes::<T>::get(&voter).last().expect("vote was cast").proposal_id;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter.clone()), proposal_id);
+
+        // Verification: Check that the vote and its stake were released
+        assert!(!ProposalVotes::<T>::contains_key(proposal_id, &voter));
+        assert_eq!(VoteCounts::<T>::get(proposal_id).yes_votes, 0);
+        assert_eq!(VoterStakes::<T>::get(&voter).len() as u32, T::MaxStakedVotes::get() - 1);
+    }
+    #[benchmark]
+    fn commit_vote() {
+        let voter: T::AccountId = whitelisted_caller();
+        let proposal_id = T::BenchmarkHelper::proposal();
+        register::<T>(&voter, None);
+        assert_ok!(CommunityVotingPallet::<T>::start_voting(
+            voting_origin::<T>(),
+            proposal_id,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Secret { reveal_blocks: 10 }
+        ));
+        let commitment = CommunityVotingPallet::<T>::commitment(proposal_id, &voter, 0, &[1; 32]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter.clone()), proposal_id, commitment);
+
+        // Verification: Check that the commitment was stored
+        assert_eq!(VoteCommitments::<T>::get(proposal_id, &voter), Some(commitment));
+        assert_eq!(VoteCounts::<T>::get(proposal_id).unrevealed, 1);
+    }
+    #[benchmark]
+    fn reveal_vote() {
+        let voter: T::AccountId = whitelisted_caller();
+        let proposal_id = T::BenchmarkHelper::proposal();
+        register::<T>(&voter, None);
+        assert_ok!(CommunityVotingPallet::<T>::start_voting(
+            voting_origin::<T>(),
+            proposal_id,
+            0,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Secret { reveal_blocks: 10 }
+        ));
+        assert_ok!(CommunityVotingPallet::<T>::commit_vote(
+            RawOrigin::Signed(voter.clone()).into(),
+            proposal_id,
+            CommunityVotingPallet::<T>::commitment(proposal_id, &voter, 0, &[1; 32])
+        ));
+        let next = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(next);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter.clone()), proposal_id, 0, [1; 32]);
+
+        // Verification: Check that the vote was counted
+        assert!(!VoteCommitments::<T>::contains_key(proposal_id, &voter));
+        assert_eq!(VoteCounts::<T>::get(proposal_id).yes_votes, 1);
+    }
+    #[benchmark]
+    fn create_ballot(n: Linear<2, { T::MaxOptions::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::VotingOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let proposal_id = T::BenchmarkHelper::proposal();
+        let options = (0..n).map(|_| [b'x'; 64].to_vec()).collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, proposal_id, 10, options, CountingMethod::InstantRunoff);
+
+        // Verification: Check that the ballot was created with every option
+        assert!(Ballots::<T>::contains_key(proposal_id));
+        assert_eq!(BallotOptions::<T>::get(proposal_id).len() as u32, n);
+        Ok(())
+    }
+    #[benchmark]
+    fn cast_ballot(n: Linear<1, { T::MaxOptions::get() }>) {
+        let voter: T::AccountId = whitelisted_caller();
+        let proposal_id = T::BenchmarkHelper::proposal();
+        register::<T>(&voter, None);
+        let options = (0..T::MaxOptions::get()).map(|_| [b'x'; 64].to_vec()).collect::<Vec<_>>();
+        assert_ok!(CommunityVotingPallet::<T>::create_ballot(
+            voting_origin::<T>(),
+            proposal_id,
+            10,
+            options,
+            CountingMethod::InstantRunoff
+        ));
+        let selection = (0..n).rev().collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter.clone()), proposal_id, selection);
+
+        // Verification: Check that the ranking was stored
+        let selection = BallotSelections::<T>::get(proposal_id, &voter).expect("ballot was cast");
+        assert_eq!(selection.len() as u32, n);
+    }
+    #[benchmark]
+    fn end_ballot() {
+        let caller: T::AccountId = whitelisted_caller();
+        let proposal_id = T::BenchmarkHelper::proposal();
+        let options = (0..T::MaxOptions::get()).map(|_| [b'x'; 64].to_vec()).collect::<Vec<_>>();
+        assert_ok!(CommunityVotingPallet::<T>::create_ballot(
+            voting_origin::<T>(),
+            proposal_id,
+            0,
+            options,
+            CountingMethod::InstantRunoff
+        ));
+        let next = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(next);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal_id);
+
+        // Verification: Check that the ballot was counted
+        assert_eq!(BallotResults::<T>::get(proposal_id), Some(BallotResult::NoVotes));
+    }
+    #[benchmark]
+    fn count_delegators() {
+        let voter: T::AccountId = whitelisted_caller();
+        let proposal_id = T::BenchmarkHelper::proposal();
+        register::<T>(&voter, None);
+        assert_ok!(CommunityVotingPallet::<T>::start_voting(
+            voting_origin::<T>(),
+            proposal_id,
+            0,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::<T>::cast_vote(
+            RawOrigin::Signed(voter.clone()).into(),
+            proposal_id,
+            0
+        ));
+
+        // Setup: The most citizens that can delegate to the voter
+        let delegators = T::MaxDelegators::get();
+        for i in 0..delegators {
+            let delegator: T::AccountId = account("delegator", i, 0);
+            register::<T>(&delegator, None);
+            assert_ok!(CommunityVotingPallet::<T>::delegate(
+                RawOrigin::Signed(delegator).into(),
+                voter.clone(),
+                None
+            ));
+        }
+        let next = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(next);
+        DelegationCounts::<T>::insert(
+            proposal_id,
+            DelegationCount { voter: None, pending: BoundedVec::new() },
+        );
+
+        #[block]
+        {
+            CommunityVotingPallet::<T>::count_votes(proposal_id, 1);
+        }
+
+        // Verification: Check that every delegator was counted
+        assert_eq!(VoteCounts::<T>::get(proposal_id).yes_delegated, delegators);
+    }
+    #[benchmark]
+    fn count_selections(n: Linear<1, { T::MaxSelectionsPerBlock::get() }>) {
+        let proposal_id = T::BenchmarkHelper::proposal();
+        let options = T::MaxOptions::get();
+        let labels = (0..options).map(|_| [b'x'; 64].to_vec()).collect::<Vec<_>>();
+        assert_ok!(CommunityVotingPallet::<T>::create_ballot(
+            voting_origin::<T>(),
+            proposal_id,
+            0,
+            labels,
+            CountingMethod::InstantRunoff
+        ));
+
+        // Setup: Voters ranking every option, one counted in each step
+        for i in 0..n {
+            let voter: T::AccountId = account("voter", i, 0);
+            let ranking = BoundedVec::truncate_from((0..options).collect::<Vec<_>>());
+            BallotSelections::<T>::insert(proposal_id, &voter, ranking);
+        }
+        let next = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(next);
+        CommunityVotingPallet::<T>::start_ballot_count(proposal_id);
+
+        #[block]
+        {
+            CommunityVotingPallet::<T>::count_ballot(proposal_id, n);
+        }
+
+        // Verification: Check that every selection was counted
+        let count = BallotCounts::<T>::get(proposal_id).unwrap();
+        assert_eq!(count.votes.first(), Some(&n));
+    }
     impl_benchmark_test_suite!(
         CommunityVotingPallet, 
         crate::mock::new_test_ext(), 
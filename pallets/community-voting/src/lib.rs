@@ -1,16 +1,85 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+use frame::traits::fungible;
+use frame::prelude::Weight;
 use scale_info::prelude::vec::Vec;
 
 
 pub mod weights;
 use crate::weights::WeightInfo;
 
+/// Handler notified of the result of each voting period when it ends.
+pub trait OnVotingEnded {
+    /// Called once the votes on `proposal_id` are counted and its result stored.
+    fn on_voting_ended(proposal_id: u64, result: VoteResult);
+
+    /// Upper bound on the weight of one `on_voting_ended` call.
+    fn on_voting_ended_weight() -> Weight;
+}
+
+impl OnVotingEnded for () {
+    fn on_voting_ended(_proposal_id: u64, _result: VoteResult) {}
+
+    fn on_voting_ended_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Information about the proposals that can be put to a vote.
+pub trait ProposalProvider {
+    /// Whether `proposal_id` refers to an existing proposal.
+    fn proposal_exists(proposal_id: u64) -> bool;
+
+    /// Category of `proposal_id` that category-specific delegations apply to, if any.
+    fn proposal_category(proposal_id: u64) -> Option<u32>;
+}
+
+impl ProposalProvider for () {
+    fn proposal_exists(_proposal_id: u64) -> bool {
+        true
+    }
+
+    fn proposal_category(_proposal_id: u64) -> Option<u32> {
+        None
+    }
+}
+
+/// Size of the electorate that turnout can be measured against.
+pub trait Electorate {
+    /// Number of registered voters.
+    fn electorate() -> u32;
+}
+
+impl Electorate for () {
+    fn electorate() -> u32 {
+        0
+    }
+}
+
+/// Helper for benchmarks to provide a proposal that can be put to a vote.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// Create a proposal known to `Config::Proposals` and return its ID.
+    fn proposal() -> u64;
+}
+
+/// Balance type of the currency staked on votes.
+pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
 #[frame::pallet]
 pub mod pallet {
     use super::*;
-    use frame::prelude::*;
+    use frame::{
+        arithmetic::Perbill,
+        prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate, MutateFreeze},
+            AtLeast32BitUnsigned, Hash, Zero,
+        },
+    };
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
@@ -22,6 +91,78 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type WeightInfo: WeightInfo;
+
+        /// Handler for voting results, such as the pallet that owns the voted proposals.
+        type OnVotingEnded: OnVotingEnded;
+
+        /// The proposals that voting periods can be started on.
+        type Proposals: ProposalProvider;
+
+        /// Maximum number of voting periods closed automatically in one block; the rest are
+        /// carried over.
+        #[pallet::constant]
+        type MaxClosuresPerBlock: Get<u32>;
+
+        /// Maximum number of steps spent counting the votes of closed voting periods in one
+        /// block, each following the delegators of one voter. Longer counts carry on in the
+        /// next blocks.
+        #[pallet::constant]
+        type MaxCountSteps: Get<u32>;
+
+        /// The overarching freeze reason.
+        type RuntimeFreezeReason: From<FreezeReason>;
+
+        /// The currency voters freeze in stake-weighted voting periods.
+        type Currency: MutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason>
+            + Mutate<Self::AccountId>;
+
+        /// Maximum number of stake-weighted votes an account can have frozen at once.
+        #[pallet::constant]
+        type MaxStakedVotes: Get<u32>;
+
+        /// Number of blocks in one conviction locking period.
+        #[pallet::constant]
+        type VoteLockingPeriod: Get<u64>;
+
+        /// The electorate that turnout fractions of `ThresholdPolicy` are measured against.
+        type Electorate: Electorate;
+
+        /// Origin allowed to register and revoke citizens in the voter registry.
+        type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to put proposals to a vote directly, outside the votes opened by
+        /// other pallets through `Pallet::open_voting`.
+        type VotingOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of accounts that can delegate to the same account.
+        #[pallet::constant]
+        type MaxDelegators: Get<u32>;
+
+        /// Maximum length of the delegation chains followed when tallying votes.
+        #[pallet::constant]
+        type MaxDelegationDepth: Get<u32>;
+
+        /// Maximum number of options on a multi-option ballot.
+        #[pallet::constant]
+        type MaxOptions: Get<u32>;
+
+        /// Maximum number of steps spent counting closed multi-option ballots in one block,
+        /// each counting one selection or concluding a counting round. Longer counts carry on
+        /// in the next blocks.
+        #[pallet::constant]
+        type MaxSelectionsPerBlock: Get<u32>;
+
+        /// Helper to create proposals in benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+    }
+
+    /// A reason for the pallet freezing funds.
+    #[pallet::composite_enum]
+    pub enum FreezeReason {
+        /// Funds staked on votes in stake-weighted voting periods.
+        #[codec(index = 0)]
+        Vote,
     }
 
     #[pallet::event]
@@ -43,6 +184,127 @@ pub mod pallet {
             /// The final result (0=Passed, 1=Failed, 2=NoQuorum).
             result: u8,
         },
+        /// A stake-weighted vote was cast on a proposal.
+        StakedVoteCast {
+            /// The proposal ID that was voted on.
+            proposal_id: u64,
+            /// The account that cast the vote.
+            voter: T::AccountId,
+            /// The vote choice (0=Yes, 1=No, 2=Abstain).
+            vote: u8,
+            /// The amount frozen.
+            stake: BalanceOf<T>,
+            /// The conviction the stake was locked with.
+            conviction: Conviction,
+            /// The weight of the vote, the stake multiplied by the conviction.
+            weight: BalanceOf<T>,
+        },
+        /// Stake on votes whose voting periods have ended was unfrozen.
+        StakeUnlocked {
+            /// The account whose stake was unfrozen.
+            voter: T::AccountId,
+            /// The amount still frozen for votes in open voting periods.
+            frozen: BalanceOf<T>,
+        },
+        /// A citizen was registered or had their registration renewed.
+        CitizenRegistered {
+            /// The registered account.
+            who: T::AccountId,
+            /// First block the registration is no longer valid, if it expires.
+            expires_at: Option<u64>,
+        },
+        /// A citizen's registration was revoked.
+        CitizenRevoked {
+            /// The account that is no longer registered.
+            who: T::AccountId,
+        },
+        /// An expired registration was removed from the voter registry.
+        RegistrationRemoved {
+            /// The account that is no longer registered.
+            who: T::AccountId,
+        },
+        /// A citizen delegated their vote.
+        Delegated {
+            /// The account delegating its vote.
+            delegator: T::AccountId,
+            /// The account voting on the delegator's behalf.
+            delegate: T::AccountId,
+            /// The proposal category delegated, or `None` for every category.
+            category: Option<u32>,
+        },
+        /// A citizen withdrew the delegation of their vote.
+        Undelegated {
+            /// The account that delegated its vote.
+            delegator: T::AccountId,
+            /// The proposal category that was delegated, or `None` for every category.
+            category: Option<u32>,
+        },
+        /// A vote on a proposal was changed.
+        VoteChanged {
+            /// The proposal ID that was voted on.
+            proposal_id: u64,
+            /// The account that changed its vote.
+            voter: T::AccountId,
+            /// The previous vote choice (0=Yes, 1=No, 2=Abstain).
+            from: u8,
+            /// The new vote choice (0=Yes, 1=No, 2=Abstain).
+            to: u8,
+        },
+        /// A vote on a proposal was retracted.
+        VoteRemoved {
+            /// The proposal ID that was voted on.
+            proposal_id: u64,
+            /// The account that retracted its vote.
+            voter: T::AccountId,
+            /// The retracted vote choice (0=Yes, 1=No, 2=Abstain).
+            vote: u8,
+        },
+        /// A secret vote was committed on a proposal.
+        VoteCommitted {
+            /// The proposal ID that was voted on.
+            proposal_id: u64,
+            /// The account that committed the vote.
+            voter: T::AccountId,
+        },
+        /// A committed vote was revealed and counted.
+        VoteRevealed {
+            /// The proposal ID that was voted on.
+            proposal_id: u64,
+            /// The account that revealed the vote.
+            voter: T::AccountId,
+            /// The vote choice (0=Yes, 1=No, 2=Abstain).
+            vote: u8,
+        },
+        /// Committed votes were not revealed before a secret ballot ended.
+        VotesUnrevealed {
+            /// The proposal ID.
+            proposal_id: u64,
+            /// The number of commitments that were not revealed.
+            count: u32,
+        },
+        /// A multi-option ballot was opened on a proposal.
+        BallotCreated {
+            /// The proposal ID.
+            proposal_id: u64,
+            /// The number of options on the ballot.
+            options: u32,
+            /// How the ballot is counted.
+            method: CountingMethod,
+        },
+        /// A multi-option ballot was cast.
+        BallotCast {
+            /// The proposal ID that was voted on.
+            proposal_id: u64,
+            /// The account that cast the ballot.
+            voter: T::AccountId,
+        },
+        /// A multi-option ballot was counted.
+        BallotEnded {
+            /// The proposal ID.
+            proposal_id: u64,
+            /// The outcome of the count.
+            result: BallotResult,
+        },
     }
     /// Storage for votes on each proposal.
     #[pallet::storage]
@@ -61,7 +323,7 @@ pub mod pallet {
         _,
         Twox64Concat,
         u64, // proposal_id
-        VoteTally,
+        VoteTally<BalanceOf<T>>,
         ValueQuery,
     >;
 
@@ -74,6 +336,168 @@ pub mod pallet {
         VotingPeriod,
     >; // REMOVED ValueQuery
 
+    /// Storage for the voting periods due to be closed at each block.
+    #[pallet::storage]
+    pub type VotingCloses<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // block number
+        Twox64Concat,
+        u64, // proposal_id
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the earliest block with closures carried over from an earlier block.
+    #[pallet::storage]
+    pub type CloseBacklog<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Storage for the multi-option ballots opened on proposals.
+    #[pallet::storage]
+    pub type Ballots<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        Ballot,
+    >;
+
+    /// Storage for the option labels of each multi-option ballot.
+    #[pallet::storage]
+    pub type BallotOptions<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BoundedVec<BoundedVec<u8, ConstU32<64>>, T::MaxOptions>,
+        ValueQuery,
+    >;
+
+    /// Storage for the options each voter selected on a multi-option ballot, in order of
+    /// preference for instant-runoff ballots.
+    #[pallet::storage]
+    pub type BallotSelections<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        Twox64Concat,
+        T::AccountId, // voter
+        BoundedVec<u32, T::MaxOptions>,
+    >;
+
+    /// Outcome of each counted multi-option ballot.
+    #[pallet::storage]
+    pub type BallotResults<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BallotResult,
+    >;
+
+    /// Storage for the closed multi-option ballots whose selections are still being counted.
+    #[pallet::storage]
+    pub type BallotCounts<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BallotCount<T>,
+    >;
+
+    /// Storage for the votes per option in each counting round of a multi-option ballot,
+    /// stored as each round is counted.
+    #[pallet::storage]
+    pub type BallotRounds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        BoundedVec<BoundedVec<u32, T::MaxOptions>, T::MaxOptions>,
+        ValueQuery,
+    >;
+
+    /// Storage for the vote commitments of secret ballots that have not been revealed yet.
+    #[pallet::storage]
+    pub type VoteCommitments<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        Twox64Concat,
+        T::AccountId, // voter
+        T::Hash, // hash of (vote, salt)
+    >;
+
+    /// Storage for the stake each account has frozen on its stake-weighted votes.
+    #[pallet::storage]
+    pub type VoterStakes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // voter
+        BoundedVec<StakedVote<BalanceOf<T>>, T::MaxStakedVotes>,
+        ValueQuery,
+    >;
+
+    /// Final result of each ended voting period.
+    #[pallet::storage]
+    pub type VotingResults<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        VoteResult,
+    >;
+
+    /// Storage for the voter registry of citizens allowed to cast one-person-one-vote votes.
+    #[pallet::storage]
+    pub type Citizens<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // citizen
+        Registration,
+    >;
+
+    /// Number of registrations in the voter registry, including expired ones not yet removed.
+    #[pallet::storage]
+    pub type CitizenCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Storage for the account each citizen delegates their vote to, globally (`None`) or per
+    /// proposal category.
+    #[pallet::storage]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // delegator
+        Twox64Concat,
+        Option<u32>, // category
+        T::AccountId, // delegate
+    >;
+
+    /// Storage for the accounts delegating to each account, with the category delegated.
+    #[pallet::storage]
+    pub type Delegators<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // delegate
+        BoundedVec<(T::AccountId, Option<u32>), T::MaxDelegators>,
+        ValueQuery,
+    >;
+
+    /// Storage for the closed voting periods whose votes are still being counted.
+    #[pallet::storage]
+    pub type DelegationCounts<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        DelegationCount<T>,
+    >;
+
+    /// Storage for the delegators already counted in each voting period, cleared once its
+    /// result is stored.
+    #[pallet::storage]
+    pub type CountedDelegators<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64, // proposal_id
+        Twox64Concat,
+        T::AccountId, // delegator
+        (),
+    >;
+
     #[pallet::error]
     pub enum Error<T> {
         /// Voting period has ended.
@@ -84,6 +508,54 @@ pub mod pallet {
         AlreadyVoted,
         /// Proposal does not exist.
         ProposalDoesNotExist,
+        /// A voting period was already started for this proposal.
+        VotingAlreadyStarted,
+        /// The voting period for this proposal has already been ended.
+        VotingAlreadyEnded,
+        /// Votes in this voting period must be cast with a stake.
+        StakeRequired,
+        /// This voting period does not weigh votes by stake.
+        NotStakeWeighted,
+        /// The stake is zero or exceeds the voter's balance.
+        InvalidStake,
+        /// The voter has too many stake-weighted votes in open voting periods.
+        TooManyStakedVotes,
+        /// None of the voter's stake can be unlocked yet.
+        NothingToUnlock,
+        /// The account is not a registered citizen.
+        NotRegistered,
+        /// The account's registration has expired.
+        RegistrationExpired,
+        /// The account's registration has not expired.
+        RegistrationNotExpired,
+        /// The registration would expire before the next block.
+        InvalidExpiry,
+        /// An account cannot delegate its vote to itself.
+        SelfDelegation,
+        /// The delegate already has the maximum number of delegators.
+        TooManyDelegators,
+        /// The account does not delegate its vote for this category.
+        NotDelegating,
+        /// The account has not voted on this proposal.
+        NotVoted,
+        /// The new vote choice is the same as the current one.
+        VoteUnchanged,
+        /// Secret ballots need a reveal phase and per-head weighting.
+        InvalidSecrecy,
+        /// Votes in this voting period must be committed and revealed.
+        SecretBallot,
+        /// This voting period does not use secret ballots.
+        NotSecretBallot,
+        /// The voting period is not in its reveal phase.
+        NotRevealPhase,
+        /// The account has no commitment to reveal for this proposal.
+        NoCommitment,
+        /// The vote and salt do not match the commitment.
+        InvalidReveal,
+        /// A ballot needs between two and `MaxOptions` options with labels of at most 64 bytes.
+        InvalidOptions,
+        /// The selection does not fit the ballot's options or counting method.
+        InvalidSelection,
     }
 
     /// Vote choice options
@@ -97,6 +569,132 @@ pub mod pallet {
         Abstain,
     }
 
+    /// How the votes in a voting period are weighed
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum VoteWeighting {
+        /// Every vote counts as one
+        #[default]
+        PerHead,
+        /// Every vote counts as the amount the voter freezes for it
+        Stake,
+    }
+
+    /// Whether the votes in a voting period are public or secret
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum Secrecy {
+        /// Votes are cast and counted openly
+        #[default]
+        Public,
+        /// Votes are committed as hashes during the voting period, then revealed
+        Secret {
+            /// Number of blocks after the voting period to reveal votes in
+            reveal_blocks: u64,
+        },
+    }
+
+    /// Minimum turnout for a voting period to reach quorum
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Turnout {
+        /// At least this many voters
+        Absolute(u32),
+        /// At least this fraction of the registered electorate
+        Electorate(Perbill),
+    }
+
+    impl Default for Turnout {
+        fn default() -> Self {
+            Turnout::Absolute(0)
+        }
+    }
+
+    /// Share of the yes and no votes that must be in favour for a proposal to pass
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum Approval {
+        /// More yes than no
+        #[default]
+        SimpleMajority,
+        /// At least 60% yes
+        SixtyPercent,
+        /// At least two thirds yes
+        TwoThirds,
+    }
+
+    impl Approval {
+        /// Whether `yes` against `no` meets this approval threshold.
+        pub fn is_met<Votes: AtLeast32BitUnsigned + Copy>(&self, yes: Votes, no: Votes) -> bool {
+            let total = yes.saturating_add(no);
+            match self {
+                Approval::SimpleMajority => yes > no,
+                Approval::SixtyPercent => {
+                    yes.saturating_mul(5u32.into()) >= total.saturating_mul(3u32.into())
+                },
+                Approval::TwoThirds => {
+                    yes.saturating_mul(3u32.into()) >= total.saturating_mul(2u32.into())
+                },
+            }
+        }
+    }
+
+    /// Turnout and approval a voting period needs for its proposal to pass
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ThresholdPolicy {
+        /// Minimum turnout, counted in voters
+        pub turnout: Turnout,
+        /// Required share of yes votes, weighed as the voting period weighs votes
+        pub approval: Approval,
+        /// Whether abstentions count toward turnout
+        pub count_abstentions: bool,
+    }
+
+    /// Conviction of a staked vote, trading a longer lock after voting ends for more weight
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum Conviction {
+        /// 0.1x weight, unlocked when voting ends
+        #[default]
+        None,
+        /// 1x weight, locked for 1 locking period after voting ends
+        Locked1x,
+        /// 2x weight, locked for 2 locking periods after voting ends
+        Locked2x,
+        /// 3x weight, locked for 4 locking periods after voting ends
+        Locked3x,
+        /// 4x weight, locked for 8 locking periods after voting ends
+        Locked4x,
+        /// 5x weight, locked for 16 locking periods after voting ends
+        Locked5x,
+        /// 6x weight, locked for 32 locking periods after voting ends
+        Locked6x,
+    }
+
+    impl Conviction {
+        /// Number of locking periods the stake stays frozen for after voting ends.
+        pub fn lock_periods(&self) -> u64 {
+            match self {
+                Conviction::None => 0,
+                Conviction::Locked1x => 1,
+                Conviction::Locked2x => 2,
+                Conviction::Locked3x => 4,
+                Conviction::Locked4x => 8,
+                Conviction::Locked5x => 16,
+                Conviction::Locked6x => 32,
+            }
+        }
+
+        /// Weight of a vote backed by `stake` at this conviction.
+        pub fn weight<Balance: AtLeast32BitUnsigned>(&self, stake: Balance) -> Balance {
+            let multiplier: u32 = match self {
+                Conviction::None => return stake / 10u32.into(),
+                Conviction::Locked1x => 1,
+                Conviction::Locked2x => 2,
+                Conviction::Locked3x => 3,
+                Conviction::Locked4x => 4,
+                Conviction::Locked5x => 5,
+                Conviction::Locked6x => 6,
+            };
+            stake.saturating_mul(multiplier.into())
+        }
+    }
+
     /// Vote result
     #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub enum VoteResult {
@@ -108,33 +706,308 @@ pub mod pallet {
         NoQuorum,
     }
 
+    /// How the selections on a multi-option ballot are counted
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum CountingMethod {
+        /// Each voter selects one option, and the option with the most votes wins
+        Plurality,
+        /// Each voter selects any number of options, and the most approved option wins
+        Approval,
+        /// Each voter ranks options, and the option with the fewest first preferences is
+        /// eliminated until one has a majority
+        InstantRunoff,
+    }
+
+    impl CountingMethod {
+        /// Count `selections` on a ballot with `options` options, returning the result and the
+        /// votes per option in each round. Plurality and approval ballots take one round.
+        ///
+        /// Selected options out of range are ignored. Options eliminated from an instant-runoff
+        /// count get no votes in later rounds, and all options tied for the fewest votes are
+        /// eliminated together.
+        pub fn count(
+            &self,
+            options: u32,
+            selections: &[Vec<u32>],
+        ) -> (BallotResult, Vec<Vec<u32>>) {
+            let options = options as usize;
+            let mut active = (0..options).map(|_| true).collect::<Vec<_>>();
+            let mut rounds = Vec::new();
+            loop {
+                let mut votes = (0..options).map(|_| 0u32).collect::<Vec<_>>();
+                for selection in selections {
+                    self.add_selection(&active, selection, &mut votes);
+                }
+                rounds.push(votes.clone());
+                if let Some(result) = self.conclude_round(&mut active, &votes) {
+                    return (result, rounds);
+                }
+            }
+        }
+
+        /// Add `selection` to the `votes` per option of a round, counting the first selected
+        /// option still `active`, or all of them for approval.
+        pub fn add_selection(&self, active: &[bool], selection: &[u32], votes: &mut [u32]) {
+            let counted = match self {
+                CountingMethod::Approval => selection.len(),
+                _ => 1,
+            };
+            let running = selection
+                .iter()
+                .filter(|option| active.get(**option as usize).copied().unwrap_or(false));
+            for option in running.take(counted) {
+                if let Some(votes) = votes.get_mut(*option as usize) {
+                    *votes = votes.saturating_add(1);
+                }
+            }
+        }
+
+        /// Conclude a round with `votes` per option, returning the result if the count is over
+        /// or otherwise eliminating the options with the fewest votes from `active`.
+        pub fn conclude_round(&self, active: &mut [bool], votes: &[u32]) -> Option<BallotResult> {
+            let total = votes.iter().fold(0u32, |total, votes| total.saturating_add(*votes));
+            if total == 0 {
+                return Some(BallotResult::NoVotes);
+            }
+            let most = votes.iter().copied().max().unwrap_or_default();
+            let leaders =
+                (0..votes.len()).filter(|option| votes[*option] == most).collect::<Vec<_>>();
+
+            if *self != CountingMethod::InstantRunoff {
+                let result = match leaders[..] {
+                    [winner] => BallotResult::Winner(winner as u32),
+                    _ => BallotResult::Tie,
+                };
+                return Some(result);
+            }
+
+            // An option with a majority of the votes still counted wins
+            if most.saturating_mul(2) > total {
+                return Some(BallotResult::Winner(leaders[0] as u32));
+            }
+
+            // Eliminate the options with the fewest votes, unless all remaining are tied
+            let fewest = (0..votes.len())
+                .filter(|option| active.get(*option).copied().unwrap_or(false))
+                .map(|option| votes[option])
+                .min()
+                .unwrap_or_default();
+            if fewest == most {
+                return Some(BallotResult::Tie);
+            }
+            for (running, votes) in active.iter_mut().zip(votes) {
+                if *votes == fewest {
+                    *running = false;
+                }
+            }
+            None
+        }
+    }
+
+    /// Outcome of a multi-option ballot
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum BallotResult {
+        /// The option at this index won
+        Winner(u32),
+        /// Options were tied for the win
+        Tie,
+        /// No ballots were cast
+        NoVotes,
+    }
+
+    /// Multi-option ballot details
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Ballot {
+        /// When voting started
+        pub start_block: u64,
+        /// When voting ends
+        pub end_block: u64,
+        /// How selections are counted
+        pub method: CountingMethod,
+    }
+
+    /// Progress of counting the selections on a closed multi-option ballot
+    #[derive(
+        Encode,
+        Decode,
+        MaxEncodedLen,
+        TypeInfo,
+        CloneNoBound,
+        PartialEqNoBound,
+        RuntimeDebugNoBound,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct BallotCount<T: Config> {
+        /// Last voter whose selection was counted in the current round
+        pub voter: Option<T::AccountId>,
+        /// Whether each option is still in the running
+        pub active: BoundedVec<bool, T::MaxOptions>,
+        /// Votes per option counted so far in the current round
+        pub votes: BoundedVec<u32, T::MaxOptions>,
+    }
+
     /// Vote tally structure
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
-    pub struct VoteTally {
+    pub struct VoteTally<Balance> {
         /// Number of yes votes
         pub yes_votes: u32,
         /// Number of no votes
         pub no_votes: u32,
         /// Number of abstain votes
         pub abstain_votes: u32,
+        /// Conviction-weighted stake behind yes votes
+        pub yes_stake: Balance,
+        /// Conviction-weighted stake behind no votes
+        pub no_stake: Balance,
+        /// Conviction-weighted stake behind abstain votes
+        pub abstain_stake: Balance,
+        /// Number of yes votes cast through delegation, added while the votes are counted
+        pub yes_delegated: u32,
+        /// Number of no votes cast through delegation, added while the votes are counted
+        pub no_delegated: u32,
+        /// Number of abstain votes cast through delegation, added while the votes are counted
+        pub abstain_delegated: u32,
+        /// Number of secret votes committed and not revealed
+        pub unrevealed: u32,
     }
 
-    /// Voting period details
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub struct VotingPeriod {
+    /// Bound on the delegators waiting in a `DelegationCount`, `MaxDelegators` for each level
+    /// of `MaxDelegationDepth`
+    pub struct MaxPendingDelegators<T>(PhantomData<T>);
+
+    impl<T: Config> Get<u32> for MaxPendingDelegators<T> {
+        fn get() -> u32 {
+            T::MaxDelegators::get().saturating_mul(T::MaxDelegationDepth::get())
+        }
+    }
+
+    /// Progress of counting the votes delegated to the direct voters of a closed voting period
+    #[derive(
+        Encode,
+        Decode,
+        MaxEncodedLen,
+        TypeInfo,
+        CloneNoBound,
+        PartialEqNoBound,
+        RuntimeDebugNoBound,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct DelegationCount<T: Config> {
+        /// Last direct voter whose delegators were followed, with their vote
+        pub voter: Option<(T::AccountId, VoteChoice)>,
+        /// Counted delegators of `voter` whose own delegators are still to be followed, with
+        /// their distance from `voter`
+        pub pending: BoundedVec<(T::AccountId, u32), MaxPendingDelegators<T>>,
+    }
+
+    /// Voting period details
+    ///
+    /// With a secret ballot, votes are committed from `start_block` to `end_block` and revealed
+    /// after it until `reveal_end_block`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VotingPeriod {
         /// When voting started
         pub start_block: u64,
         /// When voting ends
         pub end_block: u64,
+        /// How votes are weighed
+        pub weighting: VoteWeighting,
+        /// Turnout and approval needed to pass
+        pub policy: ThresholdPolicy,
+        /// When the reveal phase of a secret ballot ends
+        pub reveal_end_block: Option<u64>,
+    }
+
+    impl VotingPeriod {
+        /// Last block of the period, including the reveal phase of a secret ballot.
+        pub fn last_block(&self) -> u64 {
+            self.reveal_end_block.unwrap_or(self.end_block)
+        }
+    }
+
+    /// Age band of a registered citizen
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum AgeBand {
+        /// 18 to 24 years old
+        Youth,
+        /// 25 to 64 years old
+        Adult,
+        /// 65 years old or more
+        Senior,
     }
-        #[pallet::call]
+
+    /// Eligibility attributes recorded for a registered citizen
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct CitizenAttributes {
+        /// District the citizen lives in
+        pub district: Option<u32>,
+        /// Age band of the citizen
+        pub age_band: Option<AgeBand>,
+    }
+
+    /// Registration of a citizen in the voter registry
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Registration {
+        /// Eligibility attributes verified by the registrar
+        pub attributes: CitizenAttributes,
+        /// When the citizen was registered or last renewed
+        pub registered_at: u64,
+        /// First block the registration is no longer valid, if it expires
+        pub expires_at: Option<u64>,
+    }
+
+    /// Stake frozen for a vote in a stake-weighted voting period
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct StakedVote<Balance> {
+        /// The proposal voted on
+        pub proposal_id: u64,
+        /// The amount frozen
+        pub amount: Balance,
+        /// The weight of the vote, the amount multiplied by the conviction
+        pub weight: Balance,
+        /// Last block the amount stays frozen
+        pub locked_until: u64,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let block_number: u64 = match n.try_into() {
+                Ok(block_number) => block_number,
+                Err(_) => return Weight::zero(),
+            };
+
+            // Close the voting periods scheduled up to this block, carrying over the rest
+            let (count, visited) = Self::process_closes(block_number);
+
+            // Count the votes of closed voting periods as far as this block allows
+            let (steps, ended) = Self::process_counts(T::MaxCountSteps::get());
+
+            // Count the selections on closed ballots as far as this block allows
+            let selections = Self::process_ballot_counts(T::MaxSelectionsPerBlock::get());
+
+            let counted = T::WeightInfo::count_delegators().saturating_mul(steps.into());
+            let ended = T::OnVotingEnded::on_voting_ended_weight().saturating_mul(ended.into());
+            T::WeightInfo::close_voting_periods(count)
+                .saturating_add(counted)
+                .saturating_add(T::WeightInfo::count_selections(selections))
+                .saturating_add(ended)
+                .saturating_add(T::DbWeight::get().reads(visited.into()))
+        }
+    }
+
+    #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Start a voting period for a proposal.
         ///
-        /// The dispatch origin must be signed.
+        /// The dispatch origin must be `VotingOrigin`. The proposal must exist and can only be
+        /// put to a vote once.
         ///
         /// - `proposal_id`: The ID of the proposal to start voting on.
         /// - `duration_blocks`: How many blocks the voting period should last.
+        /// - `weighting`: Whether votes count as one each or by the stake behind them.
+        /// - `policy`: The turnout and approval needed to pass, applied when voting ends.
+        /// - `secrecy`: Whether votes are cast openly or committed and revealed afterwards.
         ///
         /// Emits `VotingPeriodEnded` event when the period ends.
         #[pallet::call_index(0)]
@@ -143,35 +1016,21 @@ pub mod pallet {
             origin: OriginFor<T>,
             proposal_id: u64,
             duration_blocks: u64,
+            weighting: VoteWeighting,
+            policy: ThresholdPolicy,
+            secrecy: Secrecy,
         ) -> DispatchResult {
-            let _starter = ensure_signed(origin)?;
-
-            // Get current block number
-            let current_block = frame_system::Pallet::<T>::block_number();
-            let start_block: u64 = current_block.try_into().map_err(|_| Error::<T>::ProposalDoesNotExist)?;
+            T::VotingOrigin::ensure_origin(origin)?;
 
-            // Calculate end block
-            let end_block = start_block + duration_blocks;
-
-            // Create voting period
-            let voting_period = VotingPeriod {
-                start_block,
-                end_block,
-            };
+            // Check if proposal exists
+            ensure!(T::Proposals::proposal_exists(proposal_id), Error::<T>::ProposalDoesNotExist);
 
-            // Store voting period
-            VotingPeriods::<T>::insert(proposal_id, voting_period);
-
-            // Initialize vote tally
-            let initial_tally = VoteTally::default();
-            VoteCounts::<T>::insert(proposal_id, initial_tally);
-
-            Ok(())
+            Self::open_voting(proposal_id, duration_blocks, weighting, policy, secrecy)
         }
 
         /// Cast a vote on a proposal.
         ///
-        /// The dispatch origin must be signed.
+        /// The dispatch origin must be signed by a citizen with a valid registration.
         ///
         /// - `proposal_id`: The ID of the proposal to vote on.
         /// - `vote`: The vote choice (0=Yes, 1=No, 2=Abstain).
@@ -186,13 +1045,58 @@ pub mod pallet {
         ) -> DispatchResult {
             let voter = ensure_signed(origin)?;
 
+            // Check if voter is a registered citizen
+            Self::ensure_registered(&voter)?;
+
             // Validate vote choice
-            let vote_choice = match vote {
-                0 => VoteChoice::Yes,
-                1 => VoteChoice::No,
-                2 => VoteChoice::Abstain,
-                _ => return Err(Error::<T>::ProposalDoesNotExist.into()),
-            };
+            let vote_choice = Self::vote_choice(vote)?;
+
+            // Check if voting is open to this voter
+            let voting_period = Self::ensure_can_vote(proposal_id, &voter)?;
+            ensure!(
+                voting_period.weighting == VoteWeighting::PerHead,
+                Error::<T>::StakeRequired
+            );
+            ensure!(voting_period.reveal_end_block.is_none(), Error::<T>::SecretBallot);
+
+            // Store the vote and update vote tally
+            Self::record_vote(proposal_id, &voter, vote_choice, Zero::zero());
+
+            // Emit event with simple u8 instead of enum
+            Self::deposit_event(Event::<T>::VoteCast {
+                proposal_id,
+                voter: voter.clone(),
+                vote,
+            });
+
+            Ok(())
+        }
+
+        /// End voting period and calculate result.
+        ///
+        /// The dispatch origin must be signed. The votes delegated to direct voters are counted
+        /// for at most `MaxCountSteps` steps, and the count carries on in the next blocks if it
+        /// needs more. Calling it again while the count is in progress continues the count. The
+        /// result is stored in `VotingResults`, so a voting period can only be ended once.
+        /// Periods not ended by the first block after their last block, the end of the reveal
+        /// phase for secret ballots, are closed automatically.
+        ///
+        /// - `proposal_id`: The ID of the proposal to end voting for.
+        ///
+        /// Emits `VotingPeriodEnded` event with the result once the votes are counted.
+        #[pallet::call_index(2)]
+        #[pallet::weight(
+            T::WeightInfo::end_voting()
+                .saturating_add(
+                    T::WeightInfo::count_delegators().saturating_mul(T::MaxCountSteps::get().into())
+                )
+                .saturating_add(T::OnVotingEnded::on_voting_ended_weight())
+        )]
+        pub fn end_voting(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let _ender = ensure_signed(origin)?;
 
             // Get voting period
             let voting_period = VotingPeriods::<T>::get(proposal_id)
@@ -202,93 +1106,1281 @@ pub mod pallet {
             let current_block = frame_system::Pallet::<T>::block_number();
             let current_block_u64: u64 = current_block.try_into().map_err(|_| Error::<T>::ProposalDoesNotExist)?;
 
-            // Check if voting period is active
+            // Check if voting period has ended
             ensure!(
-                current_block_u64 >= voting_period.start_block && 
-                current_block_u64 <= voting_period.end_block,
-                Error::<T>::VotingPeriodEnded
+                current_block_u64 > voting_period.last_block(),
+                Error::<T>::VotingPeriodNotStarted
             );
 
-            // Check if voter has already voted
+            // Check if voting was already ended
             ensure!(
-                !ProposalVotes::<T>::contains_key(proposal_id, &voter),
-                Error::<T>::AlreadyVoted
+                !VotingResults::<T>::contains_key(proposal_id),
+                Error::<T>::VotingAlreadyEnded
             );
 
-            // Store the vote
-            ProposalVotes::<T>::insert(proposal_id, &voter, vote_choice);
+            // Count the votes, or continue counting them
+            if !DelegationCounts::<T>::contains_key(proposal_id) {
+                Self::start_count(proposal_id);
+            }
+            let (steps, ended) = Self::count_votes(proposal_id, T::MaxCountSteps::get());
 
-            // Update vote tally
-            VoteCounts::<T>::mutate(proposal_id, |tally| {
-                match vote_choice {
-                    VoteChoice::Yes => tally.yes_votes += 1,
-                    VoteChoice::No => tally.no_votes += 1,
-                    VoteChoice::Abstain => tally.abstain_votes += 1,
-                }
-            });
+            let mut weight = T::WeightInfo::end_voting()
+                .saturating_add(T::WeightInfo::count_delegators().saturating_mul(steps.into()));
+            if ended {
+                weight = weight.saturating_add(T::OnVotingEnded::on_voting_ended_weight());
+            }
+            Ok(Some(weight).into())
+        }
 
-            // Emit event with simple u8 instead of enum
-            Self::deposit_event(Event::<T>::VoteCast {
+        /// Cast a vote weighted by stake on a proposal.
+        ///
+        /// The dispatch origin must be signed. The stake is frozen until the voting period
+        /// ends, plus the locking periods of the chosen conviction, and is then released with
+        /// `unlock`.
+        ///
+        /// - `proposal_id`: The ID of the proposal to vote on.
+        /// - `vote`: The vote choice (0=Yes, 1=No, 2=Abstain).
+        /// - `stake`: The amount to freeze.
+        /// - `conviction`: The multiplier applied to the stake to weigh the vote.
+        ///
+        /// Emits `StakedVoteCast` event when successful.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cast_staked_vote())]
+        pub fn cast_staked_vote(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            vote: u8,
+            stake: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            // Validate vote choice
+            let vote_choice = Self::vote_choice(vote)?;
+
+            // Check if voting is open to this voter
+            let voting_period = Self::ensure_can_vote(proposal_id, &voter)?;
+            ensure!(
+                voting_period.weighting == VoteWeighting::Stake,
+                Error::<T>::NotStakeWeighted
+            );
+
+            // Check if stake is valid
+            ensure!(
+                !stake.is_zero() && stake <= T::Currency::balance(&voter),
+                Error::<T>::InvalidStake
+            );
+
+            // Freeze the stake alongside the voter's other staked votes, for as long as the
+            // conviction requires
+            let lock_blocks = conviction.lock_periods().saturating_mul(T::VoteLockingPeriod::get());
+            let weight = conviction.weight(stake);
+            let now = Self::current_block()?;
+            let mut stakes = VoterStakes::<T>::get(&voter);
+            stakes.retain(|staked| staked.locked_until >= now);
+            stakes
+                .try_push(StakedVote {
+                    proposal_id,
+                    amount: stake,
+                    weight,
+                    locked_until: voting_period.end_block.saturating_add(lock_blocks),
+                })
+                .map_err(|_| Error::<T>::TooManyStakedVotes)?;
+            Self::update_freeze(&voter, stakes)?;
+
+            // Store the vote and update vote tally
+            Self::record_vote(proposal_id, &voter, vote_choice, weight);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::StakedVoteCast {
                 proposal_id,
-                voter: voter.clone(),
+                voter,
                 vote,
+                stake,
+                conviction,
+                weight,
             });
 
             Ok(())
         }
 
-        /// End voting period and calculate result.
+        /// Unfreeze the stake of `voter` on votes whose locks have expired.
+        ///
+        /// The dispatch origin must be signed. Anyone can unlock stake for any voter. A lock
+        /// expires once the voting period has ended and the locking periods of the vote's
+        /// conviction have passed.
+        ///
+        /// - `voter`: The account whose stake is unfrozen.
+        ///
+        /// Emits `StakeUnlocked` event when successful.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::unlock())]
+        pub fn unlock(origin: OriginFor<T>, voter: T::AccountId) -> DispatchResult {
+            let _caller = ensure_signed(origin)?;
+
+            // Drop the votes that no longer hold stake
+            let now = Self::current_block()?;
+            let mut stakes = VoterStakes::<T>::get(&voter);
+            let staked_votes = stakes.len();
+            stakes.retain(|staked| staked.locked_until >= now);
+            ensure!(stakes.len() < staked_votes, Error::<T>::NothingToUnlock);
+
+            let frozen = Self::update_freeze(&voter, stakes)?;
+
+            // Emit event
+            Self::deposit_event(Event::<T>::StakeUnlocked { voter, frozen });
+
+            Ok(())
+        }
+
+        /// Register a citizen in the voter registry, or renew their registration.
+        ///
+        /// The dispatch origin must be `RegistrarOrigin`. Registering an account that is
+        /// already registered replaces its attributes and expiry.
+        ///
+        /// - `who`: The account of the verified citizen.
+        /// - `attributes`: The citizen's eligibility attributes.
+        /// - `expires_at`: First block the registration is no longer valid, or `None` if it
+        ///   does not expire.
+        ///
+        /// Emits `CitizenRegistered` event when successful.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::register_citizen())]
+        pub fn register_citizen(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            attributes: CitizenAttributes,
+            expires_at: Option<u64>,
+        ) -> DispatchResult {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+
+            // Check if expiry is in the future
+            let now = Self::current_block()?;
+            ensure!(expires_at.map_or(true, |expiry| expiry > now), Error::<T>::InvalidExpiry);
+
+            // Store registration
+            let registration = Registration { attributes, registered_at: now, expires_at };
+            if Citizens::<T>::mutate(&who, |entry| entry.replace(registration).is_none()) {
+                CitizenCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            }
+
+            // Emit event
+            Self::deposit_event(Event::<T>::CitizenRegistered { who, expires_at });
+
+            Ok(())
+        }
+
+        /// Revoke a citizen's registration.
+        ///
+        /// The dispatch origin must be `RegistrarOrigin`. Votes already cast by the citizen
+        /// are kept.
+        ///
+        /// - `who`: The account to remove from the voter registry.
+        ///
+        /// Emits `CitizenRevoked` event when successful.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::revoke_citizen())]
+        pub fn revoke_citizen(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+
+            // Remove registration
+            Citizens::<T>::take(&who).ok_or(Error::<T>::NotRegistered)?;
+            CitizenCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            // Emit event
+            Self::deposit_event(Event::<T>::CitizenRevoked { who });
+
+            Ok(())
+        }
+
+        /// Remove an expired registration from the voter registry.
+        ///
+        /// The dispatch origin must be signed. Anyone can remove any expired registration, so
+        /// that it no longer counts towards the electorate.
+        ///
+        /// - `who`: The account whose registration has expired.
+        ///
+        /// Emits `RegistrationRemoved` event when successful.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_expired_registration())]
+        pub fn remove_expired_registration(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let _caller = ensure_signed(origin)?;
+
+            // Check if registration has expired
+            let registration = Citizens::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+            ensure!(
+                !Self::is_valid(&registration, Self::current_block()?),
+                Error::<T>::RegistrationNotExpired
+            );
+
+            // Remove registration
+            Citizens::<T>::remove(&who);
+            CitizenCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            // Emit event
+            Self::deposit_event(Event::<T>::RegistrationRemoved { who });
+
+            Ok(())
+        }
+
+        /// Delegate your vote on per-head voting periods to another account.
+        ///
+        /// The dispatch origin must be signed by a registered citizen. When a voting period
+        /// ends, the delegate's vote counts once more for every registered delegator that did
+        /// not vote directly. A delegation for a category takes precedence over a delegation
+        /// for every category. Delegating again for the same category replaces the delegate.
+        ///
+        /// - `to`: The account to vote on your behalf.
+        /// - `category`: The proposal category to delegate, or `None` for every category.
+        ///
+        /// Emits `Delegated` event when successful.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            category: Option<u32>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who != to, Error::<T>::SelfDelegation);
+
+            // Check if delegator is a registered citizen
+            Self::ensure_registered(&who)?;
+
+            // Replace any earlier delegate for this category
+            if let Some(previous) = Delegations::<T>::take(&who, category) {
+                Self::remove_delegator(&previous, &who, category);
+            }
+            Delegators::<T>::try_mutate(&to, |delegators| {
+                delegators.try_push((who.clone(), category))
+            })
+            .map_err(|_| Error::<T>::TooManyDelegators)?;
+            Delegations::<T>::insert(&who, category, &to);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Delegated { delegator: who, delegate: to, category });
+
+            Ok(())
+        }
+
+        /// Withdraw the delegation of your vote.
         ///
         /// The dispatch origin must be signed.
         ///
-        /// - `proposal_id`: The ID of the proposal to end voting for.
+        /// - `category`: The proposal category delegated, or `None` for every category.
         ///
-        /// Emits `VotingPeriodEnded` event with the result.
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::end_voting())] 
-        pub fn end_voting(
+        /// Emits `Undelegated` event when successful.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::undelegate())]
+        pub fn undelegate(origin: OriginFor<T>, category: Option<u32>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Remove delegation
+            let delegate =
+                Delegations::<T>::take(&who, category).ok_or(Error::<T>::NotDelegating)?;
+            Self::remove_delegator(&delegate, &who, category);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Undelegated { delegator: who, category });
+
+            Ok(())
+        }
+
+        /// Change your vote on a proposal while its voting period is open.
+        ///
+        /// The dispatch origin must be signed by an account that has voted on the proposal. In
+        /// per-head voting periods the account must still be a registered citizen. A staked
+        /// vote keeps its stake and conviction.
+        ///
+        /// - `proposal_id`: The ID of the proposal voted on.
+        /// - `vote`: The new vote choice (0=Yes, 1=No, 2=Abstain).
+        ///
+        /// Emits `VoteChanged` event when successful.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::change_vote())]
+        pub fn change_vote(origin: OriginFor<T>, proposal_id: u64, vote: u8) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            // Validate vote choice
+            let vote_choice = Self::vote_choice(vote)?;
+
+            // Get current vote
+            let voting_period = Self::ensure_open(proposal_id)?;
+            let previous =
+                ProposalVotes::<T>::get(proposal_id, &voter).ok_or(Error::<T>::NotVoted)?;
+            ensure!(previous != vote_choice, Error::<T>::VoteUnchanged);
+            if voting_period.weighting == VoteWeighting::PerHead {
+                Self::ensure_registered(&voter)?;
+            }
+
+            // Move the vote to its new bucket
+            let weight = Self::staked_weight(proposal_id, &voter);
+            Self::retract_vote(proposal_id, previous, weight);
+            Self::record_vote(proposal_id, &voter, vote_choice, weight);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::VoteChanged {
+                proposal_id,
+                voter,
+                from: previous as u8,
+                to: vote,
+            });
+
+            Ok(())
+        }
+
+        /// Retract your vote on a proposal while its voting period is open.
+        ///
+        /// The dispatch origin must be signed by an account that has voted on the proposal.
+        /// The stake of a retracted staked vote is no longer frozen for it. Once retracted, a
+        /// delegation covering the proposal applies again.
+        ///
+        /// - `proposal_id`: The ID of the proposal voted on.
+        ///
+        /// Emits `VoteRemoved` event when successful.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_vote())]
+        pub fn remove_vote(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            // Get current vote
+            let voting_period = Self::ensure_open(proposal_id)?;
+            let previous =
+                ProposalVotes::<T>::get(proposal_id, &voter).ok_or(Error::<T>::NotVoted)?;
+
+            // Remove the vote and release its stake
+            let weight = Self::staked_weight(proposal_id, &voter);
+            Self::retract_vote(proposal_id, previous, weight);
+            ProposalVotes::<T>::remove(proposal_id, &voter);
+            if voting_period.weighting == VoteWeighting::Stake {
+                let mut stakes = VoterStakes::<T>::get(&voter);
+                stakes.retain(|staked| staked.proposal_id != proposal_id);
+                Self::update_freeze(&voter, stakes)?;
+            }
+
+            // Emit event
+            Self::deposit_event(Event::<T>::VoteRemoved {
+                proposal_id,
+                voter,
+                vote: previous as u8,
+            });
+
+            Ok(())
+        }
+
+        /// Commit a secret vote on a proposal.
+        ///
+        /// The dispatch origin must be signed by a citizen with a valid registration, during
+        /// the voting period of a secret ballot. Committing again replaces the commitment.
+        ///
+        /// - `proposal_id`: The ID of the proposal to vote on.
+        /// - `commitment`: The hash of the proposal ID, voter, vote choice and a secret salt, see
+        ///   `commitment`.
+        ///
+        /// Emits `VoteCommitted` event when successful.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::commit_vote())]
+        pub fn commit_vote(
             origin: OriginFor<T>,
             proposal_id: u64,
+            commitment: T::Hash,
         ) -> DispatchResult {
-            let _ender = ensure_signed(origin)?;
+            let voter = ensure_signed(origin)?;
 
-            // Get voting period
+            // Check if voter is a registered citizen
+            Self::ensure_registered(&voter)?;
+
+            // Check if voting period is in its commit phase
+            let voting_period = Self::ensure_open(proposal_id)?;
+            ensure!(voting_period.reveal_end_block.is_some(), Error::<T>::NotSecretBallot);
+
+            // Store commitment, counted as unrevealed until it is revealed
+            if !VoteCommitments::<T>::contains_key(proposal_id, &voter) {
+                VoteCounts::<T>::mutate(proposal_id, |tally| {
+                    tally.unrevealed = tally.unrevealed.saturating_add(1)
+                });
+            }
+            VoteCommitments::<T>::insert(proposal_id, &voter, commitment);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::VoteCommitted { proposal_id, voter });
+
+            Ok(())
+        }
+
+        /// Reveal a committed vote so that it is counted.
+        ///
+        /// The dispatch origin must be signed by the account that committed the vote, during
+        /// the reveal phase of the voting period.
+        ///
+        /// - `proposal_id`: The ID of the proposal voted on.
+        /// - `vote`: The committed vote choice (0=Yes, 1=No, 2=Abstain).
+        /// - `salt`: The salt the vote choice was committed with.
+        ///
+        /// Emits `VoteRevealed` event when successful.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::reveal_vote())]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            vote: u8,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            // Validate vote choice
+            let vote_choice = Self::vote_choice(vote)?;
+
+            // Check if voting period is in its reveal phase
             let voting_period = VotingPeriods::<T>::get(proposal_id)
                 .ok_or(Error::<T>::ProposalDoesNotExist)?;
+            let reveal_end_block =
+                voting_period.reveal_end_block.ok_or(Error::<T>::NotSecretBallot)?;
+            let current_block = Self::current_block()?;
+            ensure!(
+                current_block > voting_period.end_block && current_block <= reveal_end_block,
+                Error::<T>::NotRevealPhase
+            );
+
+            // Check the vote against the commitment
+            let commitment = VoteCommitments::<T>::get(proposal_id, &voter)
+                .ok_or(Error::<T>::NoCommitment)?;
+            ensure!(
+                commitment == Self::commitment(proposal_id, &voter, vote, &salt),
+                Error::<T>::InvalidReveal
+            );
+
+            // Store the vote and update vote tally
+            VoteCommitments::<T>::remove(proposal_id, &voter);
+            VoteCounts::<T>::mutate(proposal_id, |tally| {
+                tally.unrevealed = tally.unrevealed.saturating_sub(1)
+            });
+            Self::record_vote(proposal_id, &voter, vote_choice, Zero::zero());
+
+            // Emit event
+            Self::deposit_event(Event::<T>::VoteRevealed { proposal_id, voter, vote });
+
+            Ok(())
+        }
+
+        /// Open a multi-option ballot on a proposal.
+        ///
+        /// The dispatch origin must be `VotingOrigin`. The proposal must exist and can only be
+        /// put to a vote once, either as a yes/no voting period or as a ballot. The ballot is
+        /// counted automatically in the first block after it ends.
+        ///
+        /// - `proposal_id`: The ID of the proposal to open the ballot on.
+        /// - `duration_blocks`: How many blocks the ballot should stay open.
+        /// - `options`: The labels of the options to choose between.
+        /// - `method`: How the selections are counted.
+        ///
+        /// Emits `BallotCreated` event when successful.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::create_ballot(options.len() as u32))]
+        pub fn create_ballot(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            duration_blocks: u64,
+            options: Vec<Vec<u8>>,
+            method: CountingMethod,
+        ) -> DispatchResult {
+            T::VotingOrigin::ensure_origin(origin)?;
+
+            // Check if proposal exists and has not been put to a vote
+            ensure!(T::Proposals::proposal_exists(proposal_id), Error::<T>::ProposalDoesNotExist);
+            ensure!(
+                !VotingPeriods::<T>::contains_key(proposal_id) &&
+                    !Ballots::<T>::contains_key(proposal_id),
+                Error::<T>::VotingAlreadyStarted
+            );
+
+            // Check if options are valid
+            ensure!(options.len() >= 2, Error::<T>::InvalidOptions);
+            let labels = options
+                .into_iter()
+                .map(BoundedVec::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| Error::<T>::InvalidOptions)?;
+            let labels: BoundedVec<_, T::MaxOptions> =
+                labels.try_into().map_err(|_| Error::<T>::InvalidOptions)?;
+
+            // Create ballot and schedule its count
+            let start_block = Self::current_block()?;
+            let end_block = start_block.saturating_add(duration_blocks);
+            Self::schedule_close(proposal_id, end_block);
+            let option_count = labels.len() as u32;
+            Ballots::<T>::insert(
+                proposal_id,
+                Ballot { start_block, end_block, method: method.clone() },
+            );
+            BallotOptions::<T>::insert(proposal_id, labels);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::BallotCreated {
+                proposal_id,
+                options: option_count,
+                method,
+            });
+
+            Ok(())
+        }
+
+        /// Cast a multi-option ballot.
+        ///
+        /// The dispatch origin must be signed by a citizen with a valid registration. A
+        /// plurality selection names exactly one option, an approval selection the approved
+        /// options and an instant-runoff selection the ranked options, most preferred first.
+        /// Options cannot be selected twice.
+        ///
+        /// - `proposal_id`: The ID of the proposal to vote on.
+        /// - `selection`: The indices of the selected options.
+        ///
+        /// Emits `BallotCast` event when successful.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::cast_ballot(selection.len() as u32))]
+        pub fn cast_ballot(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            selection: Vec<u32>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            // Check if voter is a registered citizen
+            Self::ensure_registered(&voter)?;
+
+            // Check if ballot is open to this voter
+            let ballot = Ballots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+            let current_block = Self::current_block()?;
+            ensure!(
+                current_block >= ballot.start_block && current_block <= ballot.end_block,
+                Error::<T>::VotingPeriodEnded
+            );
+            ensure!(
+                !BallotSelections::<T>::contains_key(proposal_id, &voter),
+                Error::<T>::AlreadyVoted
+            );
+
+            // Check if selection is valid
+            let options = BallotOptions::<T>::decode_len(proposal_id).unwrap_or_default() as u32;
+            ensure!(
+                !selection.is_empty() &&
+                    (ballot.method != CountingMethod::Plurality || selection.len() == 1) &&
+                    selection.iter().enumerate().all(|(index, option)| {
+                        *option < options && !selection[..index].contains(option)
+                    }),
+                Error::<T>::InvalidSelection
+            );
+            let selection: BoundedVec<u32, T::MaxOptions> =
+                selection.try_into().map_err(|_| Error::<T>::InvalidSelection)?;
+
+            // Store selection
+            BallotSelections::<T>::insert(proposal_id, &voter, selection);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::BallotCast { proposal_id, voter });
+
+            Ok(())
+        }
+
+        /// End a multi-option ballot and count it.
+        ///
+        /// The dispatch origin must be signed. The selections are counted for at most
+        /// `MaxSelectionsPerBlock` steps, and the count carries on in the next blocks if it
+        /// needs more. Calling it again while the count is in progress continues the count. The
+        /// result is stored in `BallotResults` and the votes per option in each counting round
+        /// in `BallotRounds`. Ballots not ended by the first block after `end_block` are counted
+        /// automatically.
+        ///
+        /// - `proposal_id`: The ID of the proposal to end the ballot for.
+        ///
+        /// Emits `BallotEnded` event with the result once the selections are counted.
+        #[pallet::call_index(16)]
+        #[pallet::weight(
+            T::WeightInfo::end_ballot()
+                .saturating_add(T::WeightInfo::count_selections(T::MaxSelectionsPerBlock::get()))
+        )]
+        pub fn end_ballot(origin: OriginFor<T>, proposal_id: u64) -> DispatchResultWithPostInfo {
+            let _ender = ensure_signed(origin)?;
+
+            // Check if ballot has ended
+            let ballot = Ballots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(
+                Self::current_block()? > ballot.end_block,
+                Error::<T>::VotingPeriodNotStarted
+            );
+
+            // Check if ballot was already counted
+            ensure!(
+                !BallotResults::<T>::contains_key(proposal_id),
+                Error::<T>::VotingAlreadyEnded
+            );
+
+            // Count the selections, or continue counting them
+            if !BallotCounts::<T>::contains_key(proposal_id) {
+                Self::start_ballot_count(proposal_id);
+            }
+            let (steps, _) = Self::count_ballot(proposal_id, T::MaxSelectionsPerBlock::get());
+
+            let weight =
+                T::WeightInfo::end_ballot().saturating_add(T::WeightInfo::count_selections(steps));
+            Ok(Some(weight).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Open a voting period of `duration_blocks` on `proposal_id`, starting now.
+        ///
+        /// Used by `start_voting` and by other pallets that put their proposals to a vote.
+        pub fn open_voting(
+            proposal_id: u64,
+            duration_blocks: u64,
+            weighting: VoteWeighting,
+            policy: ThresholdPolicy,
+            secrecy: Secrecy,
+        ) -> DispatchResult {
+            // Check if voting was already started
+            ensure!(
+                !VotingPeriods::<T>::contains_key(proposal_id) &&
+                    !Ballots::<T>::contains_key(proposal_id),
+                Error::<T>::VotingAlreadyStarted
+            );
+
+            // Check if a secret ballot can be revealed and counted per head
+            if let Secrecy::Secret { reveal_blocks } = secrecy {
+                ensure!(
+                    reveal_blocks > 0 && weighting == VoteWeighting::PerHead,
+                    Error::<T>::InvalidSecrecy
+                );
+            }
 
             // Get current block number
             let current_block = frame_system::Pallet::<T>::block_number();
-            let current_block_u64: u64 = current_block.try_into().map_err(|_| Error::<T>::ProposalDoesNotExist)?;
+            let start_block: u64 = current_block.try_into().map_err(|_| Error::<T>::ProposalDoesNotExist)?;
 
-            // Check if voting period has ended
+            // Calculate end block
+            let end_block = start_block.saturating_add(duration_blocks);
+            let reveal_end_block = match secrecy {
+                Secrecy::Public => None,
+                Secrecy::Secret { reveal_blocks } => Some(end_block.saturating_add(reveal_blocks)),
+            };
+
+            // Create voting period
+            let voting_period = VotingPeriod {
+                start_block,
+                end_block,
+                weighting,
+                policy,
+                reveal_end_block,
+            };
+
+            // Schedule automatic closure
+            Self::schedule_close(proposal_id, voting_period.last_block());
+
+            // Store voting period
+            VotingPeriods::<T>::insert(proposal_id, voting_period);
+
+            // Initialize vote tally
+            let initial_tally = VoteTally::default();
+            VoteCounts::<T>::insert(proposal_id, initial_tally);
+
+            Ok(())
+        }
+
+        /// Queue `proposal_id` to be closed automatically after `last_block`.
+        fn schedule_close(proposal_id: u64, last_block: u64) {
+            VotingCloses::<T>::insert(last_block.saturating_add(1), proposal_id, ());
+        }
+
+        /// Close at most `MaxClosuresPerBlock` voting periods queued up to `block_number`, oldest
+        /// first, carrying over the rest to the next block. Closed voting periods are counted
+        /// by `process_counts` and closed ballots by `process_ballot_counts`, and those already
+        /// ended manually are skipped.
+        ///
+        /// Returns the number of voting periods and blocks processed.
+        fn process_closes(block_number: u64) -> (u32, u32) {
+            let max = T::MaxClosuresPerBlock::get();
+            let mut count = 0u32;
+            let mut visited = 0u32;
+            let mut at = CloseBacklog::<T>::take().unwrap_or(block_number);
+            loop {
+                visited = visited.saturating_add(1);
+                let remaining = max.saturating_sub(count) as usize;
+                for (proposal_id, ()) in VotingCloses::<T>::drain_prefix(at).take(remaining) {
+                    if Ballots::<T>::contains_key(proposal_id) {
+                        if !BallotResults::<T>::contains_key(proposal_id) &&
+                            !BallotCounts::<T>::contains_key(proposal_id)
+                        {
+                            Self::start_ballot_count(proposal_id);
+                        }
+                    } else if !VotingResults::<T>::contains_key(proposal_id) &&
+                        !DelegationCounts::<T>::contains_key(proposal_id)
+                    {
+                        Self::start_count(proposal_id);
+                    }
+                    count = count.saturating_add(1);
+                }
+
+                // Check if closures are left over
+                if VotingCloses::<T>::contains_prefix(at) {
+                    CloseBacklog::<T>::put(at);
+                    break;
+                }
+                if at >= block_number {
+                    break;
+                }
+                at = at.saturating_add(1);
+                if visited >= max {
+                    CloseBacklog::<T>::put(at);
+                    break;
+                }
+            }
+            (count, visited)
+        }
+
+        /// Result of weighed `yes` and `no` votes from `tally` under `policy`.
+        ///
+        /// There is no quorum when turnout falls short or no yes or no votes were cast.
+        fn apply_policy<Votes: AtLeast32BitUnsigned + Copy>(
+            policy: &ThresholdPolicy,
+            tally: &VoteTally<BalanceOf<T>>,
+            yes: Votes,
+            no: Votes,
+        ) -> VoteResult {
+            // Check turnout
+            let mut turnout = tally
+                .yes_votes
+                .saturating_add(tally.yes_delegated)
+                .saturating_add(tally.no_votes)
+                .saturating_add(tally.no_delegated);
+            if policy.count_abstentions {
+                turnout = turnout
+                    .saturating_add(tally.abstain_votes)
+                    .saturating_add(tally.abstain_delegated);
+            }
+            let required = match &policy.turnout {
+                Turnout::Absolute(voters) => *voters,
+                Turnout::Electorate(fraction) => fraction.mul_ceil(T::Electorate::electorate()),
+            };
+            if turnout < required || (yes.is_zero() && no.is_zero()) {
+                return VoteResult::NoQuorum;
+            }
+
+            // Check approval
+            if policy.approval.is_met(yes, no) {
+                VoteResult::Passed
+            } else {
+                VoteResult::Failed
+            }
+        }
+
+        /// Commitment of `voter` to `vote` on `proposal_id` with `salt` for `commit_vote`.
+        ///
+        /// Binding the proposal and voter keeps a published commitment from being copied to
+        /// another voter or proposal.
+        pub fn commitment(
+            proposal_id: u64,
+            voter: &T::AccountId,
+            vote: u8,
+            salt: &[u8; 32],
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(proposal_id, voter, vote, salt))
+        }
+
+        /// Whether `who` is a registered citizen whose registration has not expired.
+        pub fn is_registered(who: &T::AccountId) -> bool {
+            match (Citizens::<T>::get(who), Self::current_block()) {
+                (Some(registration), Ok(now)) => Self::is_valid(&registration, now),
+                _ => false,
+            }
+        }
+
+        /// Whether `registration` is still valid at block `now`.
+        fn is_valid(registration: &Registration, now: u64) -> bool {
+            registration.expires_at.map_or(true, |expiry| now < expiry)
+        }
+
+        /// Check that `who` has a valid registration in the voter registry.
+        fn ensure_registered(who: &T::AccountId) -> DispatchResult {
+            let registration = Citizens::<T>::get(who).ok_or(Error::<T>::NotRegistered)?;
             ensure!(
-                current_block_u64 > voting_period.end_block,
-                Error::<T>::VotingPeriodNotStarted
+                Self::is_valid(&registration, Self::current_block()?),
+                Error::<T>::RegistrationExpired
             );
+            Ok(())
+        }
+
+        /// Remove `delegator`'s delegation for `category` from the delegators of `delegate`.
+        fn remove_delegator(
+            delegate: &T::AccountId,
+            delegator: &T::AccountId,
+            category: Option<u32>,
+        ) {
+            Delegators::<T>::mutate_exists(delegate, |delegators| {
+                if let Some(list) = delegators {
+                    list.retain(|(account, scope)| !(account == delegator && *scope == category));
+                    if list.is_empty() {
+                        *delegators = None;
+                    }
+                }
+            });
+        }
+
+        /// The account `delegator` delegates its vote on proposals of `category` to.
+        pub fn delegate_for(
+            delegator: &T::AccountId,
+            category: Option<u32>,
+        ) -> Option<T::AccountId> {
+            category
+                .and_then(|category| Delegations::<T>::get(delegator, Some(category)))
+                .or_else(|| Delegations::<T>::get(delegator, None))
+        }
+
+        /// Count the delegators of `delegate` on `proposal_id` as voting `vote`, and queue them
+        /// in `pending` to have their own delegators counted while within `MaxDelegationDepth`
+        /// of the direct voter. `delegate` is `depth` delegations away from the direct voter.
+        ///
+        /// Delegators that voted directly, are no longer registered, delegate this category to
+        /// someone else or were already counted are skipped. A delegator delegating to
+        /// `delegate` both globally and for the category is counted once.
+        fn count_delegators(
+            proposal_id: u64,
+            delegate: &T::AccountId,
+            vote: VoteChoice,
+            depth: u32,
+            category: Option<u32>,
+            tally: &mut VoteTally<BalanceOf<T>>,
+            pending: &mut BoundedVec<(T::AccountId, u32), MaxPendingDelegators<T>>,
+        ) {
+            let depth = depth.saturating_add(1);
+            if depth > T::MaxDelegationDepth::get() {
+                return;
+            }
+            for (delegator, scope) in Delegators::<T>::get(delegate) {
+                // Count each delegator once, through the delegation applying to `category`
+                let applicable =
+                    category.filter(|c| Delegations::<T>::contains_key(&delegator, Some(*c)));
+                let counted = scope == applicable &&
+                    Self::delegate_for(&delegator, category).as_ref() == Some(delegate) &&
+                    !ProposalVotes::<T>::contains_key(proposal_id, &delegator) &&
+                    !CountedDelegators::<T>::contains_key(proposal_id, &delegator) &&
+                    Self::is_registered(&delegator);
+                if !counted {
+                    continue;
+                }
+
+                let bucket = match vote {
+                    VoteChoice::Yes => &mut tally.yes_delegated,
+                    VoteChoice::No => &mut tally.no_delegated,
+                    VoteChoice::Abstain => &mut tally.abstain_delegated,
+                };
+                *bucket = bucket.saturating_add(1);
+                CountedDelegators::<T>::insert(proposal_id, &delegator, ());
+                if depth < T::MaxDelegationDepth::get() {
+                    // Fits, as at most `MaxDelegators` wait for each level of depth
+                    let _ = pending.try_push((delegator, depth));
+                }
+            }
+        }
 
+        /// The direct voter on `proposal_id` stored after `previous`, or the first one, with
+        /// their vote.
+        fn next_voter(
+            proposal_id: u64,
+            previous: Option<&T::AccountId>,
+        ) -> Option<(T::AccountId, VoteChoice)> {
+            match previous {
+                Some(previous) => ProposalVotes::<T>::iter_prefix_from(
+                    proposal_id,
+                    ProposalVotes::<T>::hashed_key_for(proposal_id, previous),
+                )
+                .next(),
+                None => ProposalVotes::<T>::iter_prefix(proposal_id).next(),
+            }
+        }
+
+        /// Start counting the votes of the closed voting period on `proposal_id`.
+        fn start_count(proposal_id: u64) {
+            DelegationCounts::<T>::insert(
+                proposal_id,
+                DelegationCount { voter: None, pending: BoundedVec::new() },
+            );
+        }
+
+        /// Count the votes of the closed voting period on `proposal_id` for at most `steps`
+        /// steps, storing its result once every direct voter of a per-head period has had
+        /// their delegators followed. Each step follows the delegators of one voter, stores the
+        /// result or clears up to `MaxDelegators` of the delegators counted and commitments
+        /// never revealed.
+        ///
+        /// Returns the number of steps taken and whether the result was stored.
+        pub(crate) fn count_votes(proposal_id: u64, steps: u32) -> (u32, bool) {
+            let Some(mut count) = DelegationCounts::<T>::get(proposal_id) else {
+                return (0, false);
+            };
+            let per_head = VotingPeriods::<T>::get(proposal_id)
+                .map_or(true, |period| period.weighting == VoteWeighting::PerHead);
+            let category = T::Proposals::proposal_category(proposal_id);
+            let mut tally = VoteCounts::<T>::get(proposal_id);
+            let mut taken = 0u32;
+            let mut counted = false;
+            let mut ended = false;
+            while taken < steps {
+                taken = taken.saturating_add(1);
+
+                // Clear the delegators counted and the commitments never revealed once the
+                // result is stored
+                if VotingResults::<T>::contains_key(proposal_id) {
+                    let limit = T::MaxDelegators::get() as usize;
+                    let cleared = CountedDelegators::<T>::drain_prefix(proposal_id)
+                        .take(limit)
+                        .count();
+                    VoteCommitments::<T>::drain_prefix(proposal_id)
+                        .take(limit.saturating_sub(cleared))
+                        .for_each(drop);
+                    if !CountedDelegators::<T>::contains_prefix(proposal_id) &&
+                        !VoteCommitments::<T>::contains_prefix(proposal_id)
+                    {
+                        DelegationCounts::<T>::remove(proposal_id);
+                        return (taken, ended);
+                    }
+                    continue;
+                }
+
+                // Follow the delegators of the next delegator waiting, or of the next voter
+                let waiting = count.pending.pop();
+                let next = match (waiting, count.voter.as_ref().map(|(_, vote)| *vote)) {
+                    (Some((delegator, depth)), Some(vote)) => Some((delegator, vote, depth)),
+                    _ if per_head => {
+                        let previous = count.voter.as_ref().map(|(voter, _)| voter);
+                        Self::next_voter(proposal_id, previous).map(|(voter, vote)| {
+                            count.voter = Some((voter.clone(), vote));
+                            (voter, vote, 0)
+                        })
+                    },
+                    _ => None,
+                };
+                match next {
+                    Some((delegate, vote, depth)) => {
+                        Self::count_delegators(
+                            proposal_id,
+                            &delegate,
+                            vote,
+                            depth,
+                            category,
+                            &mut tally,
+                            &mut count.pending,
+                        );
+                        counted = true;
+                    },
+                    None => {
+                        // Every voter was followed, so the result can be stored
+                        VoteCounts::<T>::insert(proposal_id, &tally);
+                        Self::finalize_voting(proposal_id);
+                        ended = true;
+                    },
+                }
+            }
+
+            if counted && !ended {
+                VoteCounts::<T>::insert(proposal_id, &tally);
+            }
+            DelegationCounts::<T>::insert(proposal_id, count);
+            (taken, ended)
+        }
+
+        /// Count the votes of the closed voting periods for at most `steps` steps in total.
+        ///
+        /// Returns the number of steps taken and of voting periods whose result was stored.
+        fn process_counts(steps: u32) -> (u32, u32) {
+            let mut taken = 0u32;
+            let mut ended = 0u32;
+            // Every count takes at least one step
+            let counting =
+                DelegationCounts::<T>::iter_keys().take(steps as usize).collect::<Vec<_>>();
+            for proposal_id in counting {
+                let (count_steps, count_ended) =
+                    Self::count_votes(proposal_id, steps.saturating_sub(taken));
+                taken = taken.saturating_add(count_steps);
+                ended = ended.saturating_add(count_ended as u32);
+                if taken >= steps {
+                    break;
+                }
+            }
+            (taken, ended)
+        }
+
+        /// Parse a vote choice (0=Yes, 1=No, 2=Abstain).
+        fn vote_choice(vote: u8) -> Result<VoteChoice, DispatchError> {
+            match vote {
+                0 => Ok(VoteChoice::Yes),
+                1 => Ok(VoteChoice::No),
+                2 => Ok(VoteChoice::Abstain),
+                _ => Err(Error::<T>::ProposalDoesNotExist.into()),
+            }
+        }
+
+        /// The current block number as `u64`.
+        fn current_block() -> Result<u64, DispatchError> {
+            let current_block = frame_system::Pallet::<T>::block_number();
+            current_block.try_into().map_err(|_| Error::<T>::ProposalDoesNotExist.into())
+        }
+
+        /// Check that `voter` can still vote on `proposal_id` and return its voting period.
+        fn ensure_can_vote(
+            proposal_id: u64,
+            voter: &T::AccountId,
+        ) -> Result<VotingPeriod, DispatchError> {
+            let voting_period = Self::ensure_open(proposal_id)?;
+
+            // Check if voter has already voted
+            ensure!(
+                !ProposalVotes::<T>::contains_key(proposal_id, voter),
+                Error::<T>::AlreadyVoted
+            );
+
+            Ok(voting_period)
+        }
+
+        /// Check that the voting period on `proposal_id` is open and return it.
+        fn ensure_open(proposal_id: u64) -> Result<VotingPeriod, DispatchError> {
+            // Get voting period
+            let voting_period = VotingPeriods::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+            // Check if voting period is active
+            let current_block = Self::current_block()?;
+            ensure!(
+                current_block >= voting_period.start_block &&
+                    current_block <= voting_period.end_block,
+                Error::<T>::VotingPeriodEnded
+            );
+
+            Ok(voting_period)
+        }
+
+        /// Weight of the staked vote of `voter` on `proposal_id`, or zero for per-head votes.
+        fn staked_weight(proposal_id: u64, voter: &T::AccountId) -> BalanceOf<T> {
+            VoterStakes::<T>::get(voter)
+                .iter()
+                .find(|staked| staked.proposal_id == proposal_id)
+                .map_or_else(Zero::zero, |staked| staked.weight)
+        }
+
+        /// Take a vote, with its weighted `stake`, out of the tally of `proposal_id`. The stored
+        /// vote is left for the caller to replace or remove.
+        fn retract_vote(proposal_id: u64, vote_choice: VoteChoice, stake: BalanceOf<T>) {
+            VoteCounts::<T>::mutate(proposal_id, |tally| match vote_choice {
+                VoteChoice::Yes => {
+                    tally.yes_votes = tally.yes_votes.saturating_sub(1);
+                    tally.yes_stake = tally.yes_stake.saturating_sub(stake);
+                },
+                VoteChoice::No => {
+                    tally.no_votes = tally.no_votes.saturating_sub(1);
+                    tally.no_stake = tally.no_stake.saturating_sub(stake);
+                },
+                VoteChoice::Abstain => {
+                    tally.abstain_votes = tally.abstain_votes.saturating_sub(1);
+                    tally.abstain_stake = tally.abstain_stake.saturating_sub(stake);
+                },
+            });
+        }
+
+        /// Store a vote and add it, with its weighted `stake`, to the tally of `proposal_id`.
+        fn record_vote(
+            proposal_id: u64,
+            voter: &T::AccountId,
+            vote_choice: VoteChoice,
+            stake: BalanceOf<T>,
+        ) {
+            ProposalVotes::<T>::insert(proposal_id, voter, vote_choice);
+            VoteCounts::<T>::mutate(proposal_id, |tally| match vote_choice {
+                VoteChoice::Yes => {
+                    tally.yes_votes += 1;
+                    tally.yes_stake = tally.yes_stake.saturating_add(stake);
+                },
+                VoteChoice::No => {
+                    tally.no_votes += 1;
+                    tally.no_stake = tally.no_stake.saturating_add(stake);
+                },
+                VoteChoice::Abstain => {
+                    tally.abstain_votes += 1;
+                    tally.abstain_stake = tally.abstain_stake.saturating_add(stake);
+                },
+            });
+        }
+
+        /// Store the staked votes of `voter` and freeze the largest stake among them, which
+        /// covers all of them. Returns the amount left frozen.
+        fn update_freeze(
+            voter: &T::AccountId,
+            stakes: BoundedVec<StakedVote<BalanceOf<T>>, T::MaxStakedVotes>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let frozen =
+                stakes.iter().map(|staked| staked.amount).max().unwrap_or_else(Zero::zero);
+            let reason = FreezeReason::Vote.into();
+            if frozen.is_zero() {
+                T::Currency::thaw(&reason, voter)?;
+                VoterStakes::<T>::remove(voter);
+            } else {
+                T::Currency::set_freeze(&reason, voter, frozen)?;
+                VoterStakes::<T>::insert(voter, stakes);
+            }
+            Ok(frozen)
+        }
+
+        /// Start counting the selections on the closed multi-option ballot on `proposal_id`.
+        pub(crate) fn start_ballot_count(proposal_id: u64) {
+            let options = BallotOptions::<T>::decode_len(proposal_id).unwrap_or_default();
+            BallotCounts::<T>::insert(
+                proposal_id,
+                BallotCount {
+                    voter: None,
+                    active: BoundedVec::truncate_from(
+                        (0..options).map(|_| true).collect::<Vec<_>>(),
+                    ),
+                    votes: BoundedVec::truncate_from((0..options).map(|_| 0).collect::<Vec<_>>()),
+                },
+            );
+        }
+
+        /// Count the selections on the closed multi-option ballot on `proposal_id` for at most
+        /// `steps` steps, storing each counting round as it is concluded and the result once
+        /// the count is over. Each step counts one selection or concludes a round.
+        ///
+        /// Returns the number of steps taken and whether the result was stored.
+        pub(crate) fn count_ballot(proposal_id: u64, steps: u32) -> (u32, bool) {
+            let Some(count) = BallotCounts::<T>::get(proposal_id) else {
+                return (0, false);
+            };
+            let method = match Ballots::<T>::get(proposal_id) {
+                Some(ballot) => ballot.method,
+                None => return (0, false),
+            };
+            let mut voter = count.voter;
+            let mut active = count.active.into_inner();
+            let mut votes = count.votes.into_inner();
+            let mut taken = 0u32;
+            while taken < steps {
+                taken = taken.saturating_add(1);
+
+                // Count the selection stored after the last one counted in this round
+                let next = match voter.as_ref() {
+                    Some(previous) => BallotSelections::<T>::iter_prefix_from(
+                        proposal_id,
+                        BallotSelections::<T>::hashed_key_for(proposal_id, previous),
+                    )
+                    .next(),
+                    None => BallotSelections::<T>::iter_prefix(proposal_id).next(),
+                };
+                if let Some((next_voter, selection)) = next {
+                    method.add_selection(&active, &selection, &mut votes);
+                    voter = Some(next_voter);
+                    continue;
+                }
+
+                // Every selection was counted, so the round can be concluded. Rounds fit, as
+                // every round but the last eliminates an option.
+                BallotRounds::<T>::mutate(proposal_id, |rounds| {
+                    let _ = rounds.try_push(BoundedVec::truncate_from(votes.clone()));
+                });
+                if let Some(result) = method.conclude_round(&mut active, &votes) {
+                    BallotCounts::<T>::remove(proposal_id);
+                    BallotResults::<T>::insert(proposal_id, result);
+                    Self::deposit_event(Event::<T>::BallotEnded { proposal_id, result });
+                    return (taken, true);
+                }
+                voter = None;
+                votes.iter_mut().for_each(|votes| *votes = 0);
+            }
+
+            BallotCounts::<T>::insert(
+                proposal_id,
+                BallotCount {
+                    voter,
+                    active: BoundedVec::truncate_from(active),
+                    votes: BoundedVec::truncate_from(votes),
+                },
+            );
+            (taken, false)
+        }
+
+        /// Count the selections on the closed multi-option ballots for at most `steps` steps
+        /// in total.
+        ///
+        /// Returns the number of steps taken.
+        fn process_ballot_counts(steps: u32) -> u32 {
+            let mut taken = 0u32;
+            // Every count takes at least one step
+            let counting = BallotCounts::<T>::iter_keys().take(steps as usize).collect::<Vec<_>>();
+            for proposal_id in counting {
+                let (count_steps, _) = Self::count_ballot(proposal_id, steps.saturating_sub(taken));
+                taken = taken.saturating_add(count_steps);
+                if taken >= steps {
+                    break;
+                }
+            }
+            taken
+        }
+
+        /// Compute, store and announce the result of the voting period on `proposal_id`, once
+        /// its delegated votes are counted.
+        fn finalize_voting(proposal_id: u64) {
             // Get vote tally
             let vote_tally = VoteCounts::<T>::get(proposal_id);
 
-            // Calculate result (simple majority)
-            let total_votes = vote_tally.yes_votes + vote_tally.no_votes;
-            let result = if total_votes == 0 {
-                2 // NoQuorum
-            } else if vote_tally.yes_votes > vote_tally.no_votes {
-                0 // Passed
-            } else {
-                1 // Failed
+            // Get weighting and threshold policy of the period
+            let (weighting, policy) = VotingPeriods::<T>::get(proposal_id)
+                .map(|period| (period.weighting, period.policy))
+                .unwrap_or_default();
+
+            // Report the secret votes that were never revealed
+            if vote_tally.unrevealed > 0 {
+                Self::deposit_event(Event::<T>::VotesUnrevealed {
+                    proposal_id,
+                    count: vote_tally.unrevealed,
+                });
+            }
+
+            // Calculate result under the period's threshold policy
+            let result = match weighting {
+                VoteWeighting::PerHead => Self::apply_policy(
+                    &policy,
+                    &vote_tally,
+                    vote_tally.yes_votes.saturating_add(vote_tally.yes_delegated),
+                    vote_tally.no_votes.saturating_add(vote_tally.no_delegated),
+                ),
+                VoteWeighting::Stake => Self::apply_policy(
+                    &policy,
+                    &vote_tally,
+                    vote_tally.yes_stake,
+                    vote_tally.no_stake,
+                ),
             };
 
+            // Store result
+            VotingResults::<T>::insert(proposal_id, result);
+
+            // Let the owner of the proposal act on the result
+            T::OnVotingEnded::on_voting_ended(proposal_id, result);
+
             // Emit event with simple u8 instead of enum
             Self::deposit_event(Event::<T>::VotingPeriodEnded {
                 proposal_id,
-                result,
+                result: result as u8,
             });
-
-            Ok(())
         }
     }
 }
+/// The registered citizens make up the electorate.
+impl<T: Config> Electorate for Pallet<T> {
+    fn electorate() -> u32 {
+        CitizenCount::<T>::get()
+    }
+}
+
 #[cfg(test)]
 mod mock;
 
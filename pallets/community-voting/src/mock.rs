@@ -1,5 +1,6 @@
 use crate as community_voting_pallet;
 use frame::{prelude::*, runtime::prelude::*, testing_prelude::*};
+use polkadot_sdk::pallet_balances;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -25,24 +26,142 @@ mod runtime {
 
     #[runtime::pallet_index(1)]
     pub type CommunityVotingPallet = community_voting_pallet;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances;
 }
 
 // System pallet configuration
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+// Balances pallet configuration
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type AccountStore = System;
+}
+
+/// Balance of every account endowed by `new_test_ext`.
+pub const ENDOWMENT: u128 = 1000;
+
+parameter_types! {
+    /// Results passed to `OnVotingEnded`, in order.
+    pub static EndedVotes: Vec<(u64, community_voting_pallet::VoteResult)> = Vec::new();
+    /// Last proposal ID handed out by `ProposalHelper`.
+    pub static BenchmarkProposal: u64 = 0;
+    /// Size of the electorate reported by `MockElectorate`.
+    pub static ElectorateSize: u32 = 10;
+    /// Steps spent counting votes in one block.
+    pub static MaxCountSteps: u32 = 100;
+    /// Steps spent counting ballot selections in one block.
+    pub static MaxSelectionsPerBlock: u32 = 100;
+}
+
+pub struct RecordEndedVotes;
+impl community_voting_pallet::OnVotingEnded for RecordEndedVotes {
+    fn on_voting_ended(proposal_id: u64, result: community_voting_pallet::VoteResult) {
+        EndedVotes::mutate(|votes| votes.push((proposal_id, result)));
+    }
+
+    fn on_voting_ended_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Proposal ID that `MockProposals` reports as missing.
+pub const UNKNOWN_PROPOSAL: u64 = 999;
+
+/// Every proposal exists except `UNKNOWN_PROPOSAL`, and proposal `n` is in category `n % 10`.
+pub struct MockProposals;
+impl community_voting_pallet::ProposalProvider for MockProposals {
+    fn proposal_exists(proposal_id: u64) -> bool {
+        proposal_id != UNKNOWN_PROPOSAL
+    }
+
+    fn proposal_category(proposal_id: u64) -> Option<u32> {
+        Some((proposal_id % 10) as u32)
+    }
+}
+
+pub struct MockElectorate;
+impl community_voting_pallet::Electorate for MockElectorate {
+    fn electorate() -> u32 {
+        ElectorateSize::get()
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ProposalHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl community_voting_pallet::BenchmarkHelper for ProposalHelper {
+    fn proposal() -> u64 {
+        BenchmarkProposal::mutate(|id| {
+            *id += 1;
+            *id
+        })
+    }
 }
 
 // CommunityVoting pallet configuration
 impl community_voting_pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = pallet_name::weights::SubstrateWeight<Test>;
+    type WeightInfo = crate::weights::SubstrateWeight<Test>;
+    type OnVotingEnded = RecordEndedVotes;
+    type Proposals = MockProposals;
+    type MaxClosuresPerBlock = ConstU32<2>;
+    type MaxCountSteps = MaxCountSteps;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type Currency = Balances;
+    type MaxStakedVotes = ConstU32<2>;
+    type VoteLockingPeriod = ConstU64<10>;
+    type Electorate = MockElectorate;
+    type RegistrarOrigin = frame_system::EnsureRoot<u64>;
+    type VotingOrigin = frame_system::EnsureRoot<u64>;
+    type MaxDelegators = ConstU32<3>;
+    type MaxDelegationDepth = ConstU32<2>;
+    type MaxOptions = ConstU32<4>;
+    type MaxSelectionsPerBlock = MaxSelectionsPerBlock;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ProposalHelper;
 }
 
-// Test externalities initialization
+// Test externalities initialization, with accounts 1 to 10 endowed with `ENDOWMENT` and
+// registered as citizens without expiry
 pub fn new_test_ext() -> TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
-        .build_storage()
-        .unwrap()
-        .into()
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=10).map(|who| (who, ENDOWMENT)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: TestExternalities = storage.into();
+    ext.execute_with(|| {
+        for who in 1..=10 {
+            community_voting_pallet::Citizens::<Test>::insert(
+                who,
+                community_voting_pallet::Registration {
+                    attributes: Default::default(),
+                    registered_at: 0,
+                    expires_at: None,
+                },
+            );
+        }
+        community_voting_pallet::CitizenCount::<Test>::put(10);
+    });
+    ext
+}
+
+// Advance to block `n`, running the pallet's `on_initialize` hook for each block
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        CommunityVotingPallet::on_initialize(next);
+    }
 }
\ No newline at end of file
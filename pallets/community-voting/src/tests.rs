@@ -1,5 +1,14 @@
-use crate::{mock::*, Error, Event};
+use crate::{
+    mock::*, AgeBand, Approval, BallotCounts, BallotResult, BallotResults, BallotRounds,
+    BallotSelections, CitizenAttributes, CitizenCount, Citizens, CloseBacklog, Conviction,
+    CountedDelegators, CountingMethod, DelegationCounts, Delegations, Delegators, Electorate,
+    Error, Event, FreezeReason, ProposalVotes, Registration, Secrecy, StakedVote,
+    ThresholdPolicy, Turnout, VoteCommitments, VoteCounts, VoteResult, VoteWeighting,
+    VoterStakes, VotingCloses, VotingPeriods, VotingResults,
+};
+use frame::arithmetic::Perbill;
 use frame::deps::sp_runtime;
+use frame::traits::fungible::InspectFreeze;
 use frame::testing_prelude::*;
 
 // Test successful voting period start
@@ -10,9 +19,12 @@ fn it_works_for_start_voting() {
         
         // Start voting period for proposal 1
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10 // 10 blocks duration
+            10, // 10 blocks duration
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Should succeed without errors
@@ -27,9 +39,12 @@ fn it_works_for_cast_vote() {
         
         // First start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Then cast a vote
@@ -59,9 +74,12 @@ fn it_works_for_end_voting() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5 // Ends at block 6
+            5, // Ends at block 6
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast some votes - make sure Yes votes win
@@ -98,9 +116,12 @@ fn it_works_for_different_vote_types() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast Yes vote
@@ -142,9 +163,12 @@ fn cast_vote_fails_for_invalid_vote() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Try to cast invalid vote (3 is not valid)
@@ -167,9 +191,12 @@ fn cast_vote_fails_for_already_voted() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast first vote
@@ -215,9 +242,12 @@ fn cast_vote_fails_when_voting_not_started() {
         
         // Start voting period starting at block 1, ending at block 11
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Should work at block 1
@@ -233,9 +263,12 @@ fn cast_vote_fails_when_voting_ended() {
         
         // Start voting period with short duration
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5 // Ends at block 6
+            5, // Ends at block 6
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Move time forward past voting period
@@ -261,9 +294,12 @@ fn end_voting_fails_when_voting_not_ended() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10 // Ends at block 11
+            10, // Ends at block 11
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Try to end voting before period ends
@@ -277,6 +313,46 @@ fn end_voting_fails_when_voting_not_ended() {
     });
 }
 
+// Test ordinary accounts cannot open votes that would block a later referendum
+#[test]
+fn start_voting_and_create_ballot_require_voting_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            CommunityVotingPallet::start_voting(
+                RuntimeOrigin::signed(1),
+                1,
+                10,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_noop!(
+            CommunityVotingPallet::create_ballot(
+                RuntimeOrigin::signed(1),
+                1,
+                10,
+                vec![b"Park".to_vec(), b"Library".to_vec()],
+                CountingMethod::Plurality
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+
+        // The referendum can still be opened on the proposal
+        assert_ok!(CommunityVotingPallet::open_voting(
+            1,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert!(VotingPeriods::<Test>::contains_key(1));
+    });
+}
+
 // Test unsigned origin fails for start_voting
 #[test]
 fn start_voting_fails_for_unsigned_origin() {
@@ -288,7 +364,10 @@ fn start_voting_fails_for_unsigned_origin() {
             CommunityVotingPallet::start_voting(
                 RuntimeOrigin::none(), // No signature
                 1,
-                10
+                10,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
             ),
             sp_runtime::traits::BadOrigin
         );
@@ -303,9 +382,12 @@ fn cast_vote_fails_for_unsigned_origin() {
         
         // Start voting first
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Try to call without signing
@@ -344,23 +426,32 @@ fn multiple_proposals_can_exist() {
         
         // Start voting for proposal 1
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Start voting for proposal 2
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(2),
+            RuntimeOrigin::root(),
             2,
-            15
+            15,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Start voting for proposal 3
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(3),
+            RuntimeOrigin::root(),
             3,
-            20
+            20,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Vote on all proposals
@@ -380,9 +471,12 @@ fn voting_result_passed() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast votes: 2 Yes, 1 No
@@ -418,9 +512,12 @@ fn voting_result_failed() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast votes: 1 Yes, 2 No
@@ -456,9 +553,12 @@ fn voting_result_no_quorum() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Don't cast any votes
@@ -491,9 +591,12 @@ fn voting_result_only_abstain_no_quorum() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast only abstain votes
@@ -526,9 +629,30 @@ fn same_user_can_vote_on_different_proposals() {
         System::set_block_number(1);
         
         // Start voting for multiple proposals
-        assert_ok!(CommunityVotingPallet::start_voting(RuntimeOrigin::signed(1), 1, 10));
-        assert_ok!(CommunityVotingPallet::start_voting(RuntimeOrigin::signed(2), 2, 10));
-        assert_ok!(CommunityVotingPallet::start_voting(RuntimeOrigin::signed(3), 3, 10));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            2,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            3,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
         
         // Same user votes on all three proposals
         assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(4), 1, 0));
@@ -547,9 +671,12 @@ fn voting_result_tie_fails() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast votes: 2 Yes, 2 No (tie)
@@ -586,9 +713,12 @@ fn voting_at_exact_start_and_end_blocks() {
         
         // Start voting period from block 1 to block 11
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10 // Ends at block 11
+            10, // Ends at block 11
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Vote at exact start block (should work)
@@ -616,9 +746,12 @@ fn end_voting_immediately_after_period() {
         
         // Start voting period ending at block 6
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            5
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Cast some votes
@@ -643,13 +776,16 @@ fn multiple_users_voting_same_proposal() {
         
         // Start voting period
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            10
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // 10 different users vote
-        for i in 2..12 {
+        for i in 1..=10 {
             let vote_choice = (i % 3) as u8; // Cycle through 0,1,2
             assert_ok!(CommunityVotingPallet::cast_vote(
                 RuntimeOrigin::signed(i),
@@ -670,11 +806,1628 @@ fn very_long_voting_duration() {
         
         // Start voting with very long duration
         assert_ok!(CommunityVotingPallet::start_voting(
-            RuntimeOrigin::signed(1),
+            RuntimeOrigin::root(),
             1,
-            100000 // Very long duration
+            100000, // Very long duration
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
         ));
         
         // Should work without issues
     });
-}
\ No newline at end of file
+}
+// Test a voting period can only be started once per proposal
+#[test]
+fn start_voting_fails_when_already_started() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_noop!(
+            CommunityVotingPallet::start_voting(
+                RuntimeOrigin::root(),
+                1,
+                0,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ),
+            Error::<Test>::VotingAlreadyStarted
+        );
+    });
+}
+
+// Test the result of a voting period is passed to `OnVotingEnded`
+#[test]
+fn end_voting_notifies_result_handler() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            2,
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0)); // Yes
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(3), 2, 1)); // No
+
+        System::set_block_number(7);
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(4), 1));
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(4), 2));
+
+        assert_eq!(EndedVotes::get(), vec![(1, VoteResult::Passed), (2, VoteResult::Failed)]);
+    });
+}
+
+// Test voting can only be started on existing proposals
+#[test]
+fn start_voting_fails_for_unknown_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            CommunityVotingPallet::start_voting(
+                RuntimeOrigin::root(),
+                UNKNOWN_PROPOSAL,
+                10,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ),
+            Error::<Test>::ProposalDoesNotExist
+        );
+    });
+}
+
+// Test the result is stored and a voting period cannot be ended twice
+#[test]
+fn end_voting_stores_result_once() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0)); // Yes
+        assert_eq!(VotingResults::<Test>::get(1), None);
+
+        System::set_block_number(7);
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1));
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Passed));
+
+        assert_noop!(
+            CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::VotingAlreadyEnded
+        );
+        assert_eq!(EndedVotes::get(), vec![(1, VoteResult::Passed)]);
+    });
+}
+
+// Test voting periods are closed automatically in the block after they end
+#[test]
+fn voting_period_closes_automatically() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0)); // Yes
+        assert!(VotingCloses::<Test>::contains_key(7, 1));
+
+        // Still open at the end block
+        run_to_block(6);
+        assert_eq!(VotingResults::<Test>::get(1), None);
+
+        run_to_block(7);
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Passed));
+        assert!(!VotingCloses::<Test>::contains_prefix(7));
+        System::assert_last_event(Event::VotingPeriodEnded { proposal_id: 1, result: 0 }.into());
+        assert_eq!(EndedVotes::get(), vec![(1, VoteResult::Passed)]);
+
+        assert_noop!(
+            CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::VotingAlreadyEnded
+        );
+    });
+}
+
+// Test closures beyond the per-block limit are carried over to the next block
+#[test]
+fn voting_period_closures_overflow_to_next_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // At most 2 closures per block in the mock
+        for proposal_id in 1..=3 {
+            assert_ok!(CommunityVotingPallet::start_voting(
+                RuntimeOrigin::root(),
+                proposal_id,
+                5,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ));
+        }
+        assert_eq!(VotingCloses::<Test>::iter_key_prefix(7).count(), 3);
+
+        run_to_block(7);
+        assert_eq!(EndedVotes::get().len(), 2);
+        assert_eq!(VotingCloses::<Test>::iter_key_prefix(7).count(), 1);
+        assert_eq!(CloseBacklog::<Test>::get(), Some(7));
+
+        run_to_block(8);
+        assert_eq!(EndedVotes::get().len(), 3);
+        for proposal_id in 1..=3 {
+            assert_eq!(VotingResults::<Test>::get(proposal_id), Some(VoteResult::NoQuorum));
+        }
+        assert!(!VotingCloses::<Test>::contains_prefix(7));
+        assert_eq!(CloseBacklog::<Test>::get(), None);
+    });
+}
+
+// Test periods ended manually are not closed again
+#[test]
+fn manually_ended_voting_period_is_not_closed_again() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            0,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        System::set_block_number(2);
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(2), 1));
+
+        CommunityVotingPallet::on_initialize(2);
+        assert!(!VotingCloses::<Test>::contains_prefix(2));
+        assert_eq!(EndedVotes::get(), vec![(1, VoteResult::NoQuorum)]);
+    });
+}
+
+// Test closures carried over across several blocks are all processed, oldest first
+#[test]
+fn voting_period_closures_catch_up_after_backlog() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // 7 periods closing at block 7 and 1 at block 8, with 2 closures per block
+        for proposal_id in 1..=7 {
+            assert_ok!(CommunityVotingPallet::start_voting(
+                RuntimeOrigin::root(),
+                proposal_id,
+                5,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ));
+        }
+        System::set_block_number(2);
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            8,
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+
+        run_to_block(9);
+        assert_eq!(EndedVotes::get().len(), 6);
+        assert_eq!(VotingResults::<Test>::get(8), None);
+        assert_eq!(CloseBacklog::<Test>::get(), Some(7));
+
+        run_to_block(10);
+        assert_eq!(EndedVotes::get().len(), 8);
+        assert_eq!(EndedVotes::get()[7], (8, VoteResult::NoQuorum));
+
+        // Blocks not looked at yet are caught up on next
+        assert_eq!(CloseBacklog::<Test>::get(), Some(9));
+        run_to_block(11);
+        assert_eq!(CloseBacklog::<Test>::get(), None);
+    });
+}
+
+// Amount of `who`'s balance frozen for staked votes
+fn frozen(who: u64) -> u128 {
+    Balances::balance_frozen(&FreezeReason::Vote.into(), &who)
+}
+
+// Test stake-weighted votes are decided by stake rather than head count
+#[test]
+fn staked_votes_are_weighted_by_stake() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(1),
+            1,
+            0,
+            100,
+            Conviction::Locked1x
+        ));
+        System::assert_last_event(
+            Event::StakedVoteCast {
+                proposal_id: 1,
+                voter: 1,
+                vote: 0,
+                stake: 100,
+                conviction: Conviction::Locked1x,
+                weight: 100,
+            }
+            .into(),
+        );
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(2),
+            1,
+            1,
+            50,
+            Conviction::Locked1x
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(3),
+            1,
+            1,
+            60,
+            Conviction::Locked1x
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(4),
+            1,
+            2,
+            10,
+            Conviction::Locked1x
+        ));
+
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_votes, tally.no_votes, tally.abstain_votes), (1, 2, 1));
+        assert_eq!((tally.yes_stake, tally.no_stake, tally.abstain_stake), (100, 110, 10));
+        assert_eq!(frozen(1), 100);
+        assert_eq!(
+            VoterStakes::<Test>::get(1).to_vec(),
+            vec![StakedVote { proposal_id: 1, amount: 100, weight: 100, locked_until: 16 }]
+        );
+
+        // More stake against than in favour
+        run_to_block(7);
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Failed));
+    });
+}
+
+// Test each kind of voting period only accepts its own kind of vote
+#[test]
+fn votes_must_match_voting_period_weighting() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            2,
+            5,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+
+        assert_noop!(
+            CommunityVotingPallet::cast_staked_vote(
+                RuntimeOrigin::signed(2),
+                1,
+                0,
+                100,
+                Conviction::Locked1x,
+            ),
+            Error::<Test>::NotStakeWeighted
+        );
+        assert_noop!(
+            CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 2, 0),
+            Error::<Test>::StakeRequired
+        );
+    });
+}
+
+// Test staked votes fail for invalid stakes or too many staked votes
+#[test]
+fn cast_staked_vote_fails_for_invalid_stake() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for proposal_id in 1..=3 {
+            assert_ok!(CommunityVotingPallet::start_voting(
+                RuntimeOrigin::root(),
+                proposal_id,
+                5,
+                VoteWeighting::Stake,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ));
+        }
+
+        assert_noop!(
+            CommunityVotingPallet::cast_staked_vote(
+                RuntimeOrigin::signed(2),
+                1,
+                0,
+                0,
+                Conviction::Locked1x,
+            ),
+            Error::<Test>::InvalidStake
+        );
+        assert_noop!(
+            CommunityVotingPallet::cast_staked_vote(
+                RuntimeOrigin::signed(2),
+                1,
+                0,
+                ENDOWMENT + 1,
+                Conviction::Locked1x,
+            ),
+            Error::<Test>::InvalidStake
+        );
+
+        // At most 2 staked votes at once in the mock
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(2),
+            1,
+            0,
+            100,
+            Conviction::Locked1x
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(2),
+            2,
+            0,
+            100,
+            Conviction::Locked1x
+        ));
+        assert_noop!(
+            CommunityVotingPallet::cast_staked_vote(
+                RuntimeOrigin::signed(2),
+                3,
+                0,
+                100,
+                Conviction::Locked1x,
+            ),
+            Error::<Test>::TooManyStakedVotes
+        );
+    });
+}
+
+// Test stake is unfrozen once the voting periods it was staked in have ended
+#[test]
+fn unlock_releases_stake_after_voting_ends() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            2,
+            10,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(2),
+            1,
+            0,
+            300,
+            Conviction::None
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(2),
+            2,
+            1,
+            100,
+            Conviction::None
+        ));
+
+        // Overlapping stakes share one freeze of the largest amount
+        assert_eq!(frozen(2), 300);
+        assert_noop!(
+            CommunityVotingPallet::unlock(RuntimeOrigin::signed(3), 2),
+            Error::<Test>::NothingToUnlock
+        );
+
+        // Anyone can unlock the stake of an ended voting period
+        run_to_block(7);
+        assert_ok!(CommunityVotingPallet::unlock(RuntimeOrigin::signed(3), 2));
+        System::assert_last_event(Event::StakeUnlocked { voter: 2, frozen: 100 }.into());
+        assert_eq!(frozen(2), 100);
+
+        run_to_block(12);
+        assert_ok!(CommunityVotingPallet::unlock(RuntimeOrigin::signed(2), 2));
+        assert_eq!(frozen(2), 0);
+        assert!(VoterStakes::<Test>::get(2).is_empty());
+        assert_noop!(
+            CommunityVotingPallet::unlock(RuntimeOrigin::signed(2), 2),
+            Error::<Test>::NothingToUnlock
+        );
+    });
+}
+
+// Test conviction multiplies vote weight and extends the lock after voting ends
+#[test]
+fn conviction_multiplies_weight_and_extends_lock() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(1),
+            1,
+            0,
+            100,
+            Conviction::Locked2x
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(2),
+            1,
+            1,
+            150,
+            Conviction::Locked1x
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(3),
+            1,
+            1,
+            400,
+            Conviction::None
+        ));
+
+        // 2x of 100 outweighs 1x of 150 plus 0.1x of 400
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_stake, tally.no_stake), (200, 190));
+        run_to_block(7);
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Passed));
+
+        // The full stake is frozen regardless of conviction
+        assert_eq!((frozen(1), frozen(2), frozen(3)), (100, 150, 400));
+
+        // Without conviction the stake unlocks when voting ends
+        assert_ok!(CommunityVotingPallet::unlock(RuntimeOrigin::signed(3), 3));
+        assert_eq!(frozen(3), 0);
+
+        // Locking periods are 10 blocks in the mock, 2 of them for `Locked2x`
+        assert_eq!(VoterStakes::<Test>::get(1)[0].locked_until, 26);
+        run_to_block(26);
+        assert_noop!(
+            CommunityVotingPallet::unlock(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::NothingToUnlock
+        );
+        run_to_block(27);
+        assert_ok!(CommunityVotingPallet::unlock(RuntimeOrigin::signed(1), 1));
+        assert_eq!(frozen(1), 0);
+        assert_eq!(frozen(2), 150);
+    });
+}
+
+// Test the conviction schedule
+#[test]
+fn conviction_schedule() {
+    let schedule = [
+        (Conviction::None, 1, 0),
+        (Conviction::Locked1x, 10, 1),
+        (Conviction::Locked2x, 20, 2),
+        (Conviction::Locked3x, 30, 4),
+        (Conviction::Locked4x, 40, 8),
+        (Conviction::Locked5x, 50, 16),
+        (Conviction::Locked6x, 60, 32),
+    ];
+    for (conviction, weight, lock_periods) in schedule {
+        assert_eq!(conviction.weight(10u128), weight);
+        assert_eq!(conviction.lock_periods(), lock_periods);
+    }
+}
+
+// Put proposal 1 to a vote under `policy`, cast `yes`, `no` and `abstain` votes from distinct
+// accounts and return the result once the voting period has closed
+fn result_under(policy: ThresholdPolicy, yes: u64, no: u64, abstain: u64) -> VoteResult {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::PerHead,
+            policy.clone(),
+            Secrecy::Public
+        ));
+        assert_eq!(VotingPeriods::<Test>::get(1).unwrap().policy, policy);
+
+        let votes = (0..yes).map(|_| 0).chain((0..no).map(|_| 1)).chain((0..abstain).map(|_| 2));
+        for (voter, vote) in (1..).zip(votes) {
+            assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(voter), 1, vote));
+        }
+
+        run_to_block(7);
+        VotingResults::<Test>::get(1).unwrap()
+    })
+}
+
+// Test the default policy is a simple majority of any turnout
+#[test]
+fn default_policy_is_simple_majority() {
+    let policy = ThresholdPolicy::default();
+    assert_eq!(result_under(policy.clone(), 1, 0, 0), VoteResult::Passed);
+    assert_eq!(result_under(policy.clone(), 2, 2, 0), VoteResult::Failed);
+    assert_eq!(result_under(policy, 0, 0, 3), VoteResult::NoQuorum);
+}
+
+// Test absolute turnout requirements, with and without abstentions
+#[test]
+fn policy_requires_absolute_turnout() {
+    let policy = ThresholdPolicy { turnout: Turnout::Absolute(3), ..Default::default() };
+    assert_eq!(result_under(policy.clone(), 2, 0, 0), VoteResult::NoQuorum);
+    assert_eq!(result_under(policy.clone(), 2, 1, 0), VoteResult::Passed);
+    assert_eq!(result_under(policy.clone(), 2, 0, 1), VoteResult::NoQuorum);
+
+    let policy = ThresholdPolicy { count_abstentions: true, ..policy };
+    assert_eq!(result_under(policy, 2, 0, 1), VoteResult::Passed);
+}
+
+// Test turnout measured as a fraction of the electorate
+#[test]
+fn policy_requires_electorate_turnout() {
+    // Half of the 10 voters in the mock electorate
+    let policy = ThresholdPolicy {
+        turnout: Turnout::Electorate(Perbill::from_percent(50)),
+        count_abstentions: true,
+        ..Default::default()
+    };
+    assert_eq!(result_under(policy.clone(), 3, 0, 1), VoteResult::NoQuorum);
+    assert_eq!(result_under(policy.clone(), 3, 1, 1), VoteResult::Passed);
+
+    ElectorateSize::set(4);
+    assert_eq!(result_under(policy, 2, 0, 0), VoteResult::Passed);
+}
+
+// Test qualified majorities
+#[test]
+fn policy_requires_approval_ratio() {
+    let sixty = ThresholdPolicy { approval: Approval::SixtyPercent, ..Default::default() };
+    assert_eq!(result_under(sixty.clone(), 3, 2, 0), VoteResult::Passed);
+    assert_eq!(result_under(sixty, 5, 4, 0), VoteResult::Failed);
+
+    let two_thirds = ThresholdPolicy { approval: Approval::TwoThirds, ..Default::default() };
+    assert_eq!(result_under(two_thirds.clone(), 3, 2, 0), VoteResult::Failed);
+    assert_eq!(result_under(two_thirds.clone(), 2, 1, 0), VoteResult::Passed);
+
+    // Abstentions do not count against approval
+    assert_eq!(result_under(two_thirds, 2, 1, 5), VoteResult::Passed);
+}
+
+// Test only registered citizens can cast per-head votes
+#[test]
+fn cast_vote_requires_registration() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+
+        assert_noop!(
+            CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(11), 1, 0),
+            Error::<Test>::NotRegistered
+        );
+
+        let attributes = CitizenAttributes { district: Some(3), age_band: Some(AgeBand::Youth) };
+        assert_ok!(CommunityVotingPallet::register_citizen(
+            RuntimeOrigin::root(),
+            11,
+            attributes.clone(),
+            None
+        ));
+        System::assert_last_event(Event::CitizenRegistered { who: 11, expires_at: None }.into());
+        assert_eq!(
+            Citizens::<Test>::get(11),
+            Some(Registration { attributes, registered_at: 1, expires_at: None })
+        );
+
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(11), 1, 0));
+        assert_eq!(VoteCounts::<Test>::get(1).yes_votes, 1);
+    });
+}
+
+// Test only the registrar can register and revoke citizens
+#[test]
+fn registry_requires_registrar_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            CommunityVotingPallet::register_citizen(
+                RuntimeOrigin::signed(1),
+                11,
+                CitizenAttributes::default(),
+                None
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_noop!(
+            CommunityVotingPallet::revoke_citizen(RuntimeOrigin::signed(1), 2),
+            sp_runtime::traits::BadOrigin
+        );
+    });
+}
+
+// Test registrations stop allowing votes once they expire
+#[test]
+fn expired_registration_cannot_vote() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            CommunityVotingPallet::register_citizen(
+                RuntimeOrigin::root(),
+                11,
+                CitizenAttributes::default(),
+                Some(1)
+            ),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_ok!(CommunityVotingPallet::register_citizen(
+            RuntimeOrigin::root(),
+            11,
+            CitizenAttributes::default(),
+            Some(3)
+        ));
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert!(CommunityVotingPallet::is_registered(&11));
+
+        System::set_block_number(3);
+        assert!(!CommunityVotingPallet::is_registered(&11));
+        assert_noop!(
+            CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(11), 1, 0),
+            Error::<Test>::RegistrationExpired
+        );
+
+        // Renewing the registration restores the right to vote
+        assert_ok!(CommunityVotingPallet::register_citizen(
+            RuntimeOrigin::root(),
+            11,
+            CitizenAttributes::default(),
+            None
+        ));
+        assert_eq!(CitizenCount::<Test>::get(), 11);
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(11), 1, 0));
+    });
+}
+
+// Test revoked citizens can no longer vote and leave the electorate
+#[test]
+fn revoke_citizen_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            10,
+            VoteWeighting::PerHead,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_eq!(<CommunityVotingPallet as Electorate>::electorate(), 10);
+
+        assert_ok!(CommunityVotingPallet::revoke_citizen(RuntimeOrigin::root(), 2));
+        System::assert_last_event(Event::CitizenRevoked { who: 2 }.into());
+        assert_eq!(<CommunityVotingPallet as Electorate>::electorate(), 9);
+        assert_noop!(
+            CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            CommunityVotingPallet::revoke_citizen(RuntimeOrigin::root(), 2),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
+
+// Test anyone can remove expired registrations, but only expired ones
+#[test]
+fn remove_expired_registration_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::register_citizen(
+            RuntimeOrigin::root(),
+            11,
+            CitizenAttributes::default(),
+            Some(5)
+        ));
+        assert_noop!(
+            CommunityVotingPallet::remove_expired_registration(RuntimeOrigin::signed(1), 11),
+            Error::<Test>::RegistrationNotExpired
+        );
+        assert_noop!(
+            CommunityVotingPallet::remove_expired_registration(RuntimeOrigin::signed(1), 12),
+            Error::<Test>::NotRegistered
+        );
+
+        System::set_block_number(5);
+        assert_eq!(<CommunityVotingPallet as Electorate>::electorate(), 11);
+        assert_ok!(CommunityVotingPallet::remove_expired_registration(
+            RuntimeOrigin::signed(1),
+            11
+        ));
+        System::assert_last_event(Event::RegistrationRemoved { who: 11 }.into());
+        assert!(!Citizens::<Test>::contains_key(11));
+        assert_eq!(<CommunityVotingPallet as Electorate>::electorate(), 10);
+    });
+}
+
+// Start a 10 block per-head voting period on `proposal_id` with the default policy
+fn start_per_head(proposal_id: u64) {
+    assert_ok!(CommunityVotingPallet::start_voting(
+        RuntimeOrigin::root(),
+        proposal_id,
+        10,
+        VoteWeighting::PerHead,
+        ThresholdPolicy::default(),
+        Secrecy::Public
+    ));
+}
+
+// Test delegated votes follow the delegate's vote when voting ends
+#[test]
+fn delegated_votes_count_for_delegate() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, None));
+        System::assert_last_event(
+            Event::Delegated { delegator: 2, delegate: 1, category: None }.into(),
+        );
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, None));
+        assert_eq!(Delegators::<Test>::get(1).into_inner(), vec![(2, None), (3, None)]);
+
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(4), 1, 1));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(5), 1, 1));
+
+        run_to_block(12);
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_votes, tally.yes_delegated), (1, 2));
+        assert_eq!((tally.no_votes, tally.no_delegated), (2, 0));
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Passed));
+    });
+}
+
+// Test a direct vote overrides the delegation for that proposal
+#[test]
+fn direct_vote_overrides_delegation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, None));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 1));
+
+        run_to_block(12);
+        assert_eq!(VoteCounts::<Test>::get(1).yes_delegated, 0);
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Failed));
+    });
+}
+
+// Test a delegation for a category takes precedence over a global delegation
+#[test]
+fn category_delegation_takes_precedence() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        start_per_head(2);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, None));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 4, Some(2)));
+        assert_eq!(CommunityVotingPallet::delegate_for(&3, Some(1)), Some(1));
+        assert_eq!(CommunityVotingPallet::delegate_for(&3, Some(2)), Some(4));
+
+        for proposal_id in 1..=2 {
+            assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), proposal_id, 0));
+            assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(4), proposal_id, 1));
+        }
+
+        run_to_block(12);
+        let first = VoteCounts::<Test>::get(1);
+        assert_eq!((first.yes_delegated, first.no_delegated), (1, 0));
+        let second = VoteCounts::<Test>::get(2);
+        assert_eq!((second.yes_delegated, second.no_delegated), (0, 1));
+    });
+}
+
+// Test a delegator delegating to the same account globally and per category is counted once
+#[test]
+fn overlapping_delegations_are_counted_once() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        start_per_head(3);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, None));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, Some(1)));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, Some(2)));
+        assert_eq!(Delegators::<Test>::get(1).len(), 3);
+
+        for proposal_id in [1, 3] {
+            assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), proposal_id, 0));
+        }
+
+        run_to_block(12);
+        assert_eq!(VoteCounts::<Test>::get(1).yes_delegated, 1);
+        assert_eq!(VoteCounts::<Test>::get(3).yes_delegated, 1);
+    });
+}
+
+// Test delegated votes are counted over several blocks when there are too many for one
+#[test]
+fn delegated_votes_are_counted_across_blocks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        MaxCountSteps::set(1);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, None));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, None));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(4), 2, None));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+
+        // The voter's delegators first, then theirs
+        run_to_block(12);
+        assert_eq!(VoteCounts::<Test>::get(1).yes_delegated, 2);
+        assert_eq!(VotingResults::<Test>::get(1), None);
+        run_to_block(14);
+        assert_eq!(VoteCounts::<Test>::get(1).yes_delegated, 3);
+        assert_eq!(VotingResults::<Test>::get(1), None);
+
+        run_to_block(15);
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Passed));
+        assert_eq!(EndedVotes::get(), vec![(1, VoteResult::Passed)]);
+        assert!(DelegationCounts::<Test>::contains_key(1));
+
+        // Cleared up after the result
+        run_to_block(16);
+        assert!(!DelegationCounts::<Test>::contains_key(1));
+        assert!(!CountedDelegators::<Test>::contains_prefix(1));
+    });
+}
+
+// Test end_voting continues a count in progress until the result is stored
+#[test]
+fn end_voting_continues_count() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        MaxCountSteps::set(1);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, None));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 1));
+
+        System::set_block_number(12);
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1));
+        assert_eq!(VoteCounts::<Test>::get(1).no_delegated, 1);
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1));
+        assert_eq!(VotingResults::<Test>::get(1), None);
+        assert_ok!(CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1));
+        System::assert_last_event(Event::VotingPeriodEnded { proposal_id: 1, result: 1 }.into());
+        assert_noop!(
+            CommunityVotingPallet::end_voting(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::VotingAlreadyEnded
+        );
+    });
+}
+
+// Test a delegator moving to another voter while votes are counted is not counted twice
+#[test]
+fn delegation_changed_during_count_is_counted_once() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        MaxCountSteps::set(1);
+
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(5), 1, 1));
+        let first = ProposalVotes::<Test>::iter_key_prefix(1).next().unwrap();
+        let second = if first == 1 { 5 } else { 1 };
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), first, None));
+
+        // Counted for the first voter, then delegated to the second before it is counted
+        run_to_block(12);
+        assert!(CountedDelegators::<Test>::contains_key(1, 2));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), second, None));
+
+        run_to_block(16);
+        assert!(VotingResults::<Test>::get(1).is_some());
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!(tally.yes_delegated + tally.no_delegated, 1);
+        assert_eq!(tally.yes_delegated, (first == 1) as u32);
+    });
+}
+
+// Test delegation chains are followed up to `MaxDelegationDepth`
+#[test]
+fn delegation_chains_are_bounded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+
+        // 4 delegates to 3, which delegates to 2, which delegates to 1
+        for delegator in 2..=4 {
+            assert_ok!(CommunityVotingPallet::delegate(
+                RuntimeOrigin::signed(delegator),
+                delegator - 1,
+                None
+            ));
+        }
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+
+        run_to_block(12);
+        assert_eq!(VoteCounts::<Test>::get(1).yes_delegated, 2);
+    });
+}
+
+// Test delegators that are no longer registered are not counted
+#[test]
+fn revoked_delegators_are_not_counted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, None));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(3), 1, None));
+        assert_ok!(CommunityVotingPallet::revoke_citizen(RuntimeOrigin::root(), 2));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+
+        run_to_block(12);
+        assert_eq!(VoteCounts::<Test>::get(1).yes_delegated, 1);
+    });
+}
+
+// Test delegations can be replaced and withdrawn
+#[test]
+fn delegate_and_undelegate_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 2, None),
+            Error::<Test>::SelfDelegation
+        );
+        assert_noop!(
+            CommunityVotingPallet::delegate(RuntimeOrigin::signed(11), 1, None),
+            Error::<Test>::NotRegistered
+        );
+
+        // Delegating again replaces the delegate
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, Some(1)));
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 3, Some(1)));
+        assert!(Delegators::<Test>::get(1).is_empty());
+        assert_eq!(Delegations::<Test>::get(2, Some(1u32)), Some(3));
+
+        assert_noop!(
+            CommunityVotingPallet::undelegate(RuntimeOrigin::signed(2), None),
+            Error::<Test>::NotDelegating
+        );
+        assert_ok!(CommunityVotingPallet::undelegate(RuntimeOrigin::signed(2), Some(1)));
+        System::assert_last_event(Event::Undelegated { delegator: 2, category: Some(1) }.into());
+        assert_eq!(Delegations::<Test>::get(2, Some(1u32)), None);
+        assert!(Delegators::<Test>::get(3).is_empty());
+    });
+}
+
+// Test the number of delegators per delegate is bounded
+#[test]
+fn delegate_fails_with_too_many_delegators() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for delegator in 2..=4 {
+            assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(delegator), 1, None));
+        }
+        assert_noop!(
+            CommunityVotingPallet::delegate(RuntimeOrigin::signed(5), 1, None),
+            Error::<Test>::TooManyDelegators
+        );
+    });
+}
+
+// Test changing a vote moves it between tally buckets
+#[test]
+fn change_vote_moves_vote() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0));
+
+        assert_ok!(CommunityVotingPallet::change_vote(RuntimeOrigin::signed(2), 1, 1));
+        System::assert_last_event(
+            Event::VoteChanged { proposal_id: 1, voter: 2, from: 0, to: 1 }.into(),
+        );
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_votes, tally.no_votes, tally.abstain_votes), (0, 1, 0));
+
+        assert_noop!(
+            CommunityVotingPallet::change_vote(RuntimeOrigin::signed(2), 1, 1),
+            Error::<Test>::VoteUnchanged
+        );
+        assert_noop!(
+            CommunityVotingPallet::change_vote(RuntimeOrigin::signed(3), 1, 0),
+            Error::<Test>::NotVoted
+        );
+
+        // Votes are final once the period has ended
+        System::set_block_number(12);
+        assert_noop!(
+            CommunityVotingPallet::change_vote(RuntimeOrigin::signed(2), 1, 2),
+            Error::<Test>::VotingPeriodEnded
+        );
+    });
+}
+
+// Test a retracted vote leaves the tally and can be cast again
+#[test]
+fn remove_vote_retracts_vote() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 2));
+
+        assert_ok!(CommunityVotingPallet::remove_vote(RuntimeOrigin::signed(2), 1));
+        System::assert_last_event(Event::VoteRemoved { proposal_id: 1, voter: 2, vote: 2 }.into());
+        assert_eq!(VoteCounts::<Test>::get(1).abstain_votes, 0);
+        assert_noop!(
+            CommunityVotingPallet::remove_vote(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::NotVoted
+        );
+
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0));
+        assert_eq!(VoteCounts::<Test>::get(1).yes_votes, 1);
+    });
+}
+
+// Test staked votes keep their weight when changed and release their stake when retracted
+#[test]
+fn change_and_remove_staked_vote() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::start_voting(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            VoteWeighting::Stake,
+            ThresholdPolicy::default(),
+            Secrecy::Public
+        ));
+        assert_ok!(CommunityVotingPallet::cast_staked_vote(
+            RuntimeOrigin::signed(1),
+            1,
+            0,
+            100,
+            Conviction::Locked2x
+        ));
+
+        assert_ok!(CommunityVotingPallet::change_vote(RuntimeOrigin::signed(1), 1, 1));
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_votes, tally.no_votes), (0, 1));
+        assert_eq!((tally.yes_stake, tally.no_stake), (0, 200));
+        assert_eq!(frozen(1), 100);
+
+        assert_ok!(CommunityVotingPallet::remove_vote(RuntimeOrigin::signed(1), 1));
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.no_votes, tally.no_stake), (0, 0));
+        assert_eq!(frozen(1), 0);
+        assert!(VoterStakes::<Test>::get(1).is_empty());
+    });
+}
+
+// Test a delegation applies again once the delegator retracts their own vote
+#[test]
+fn removed_vote_restores_delegation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_per_head(1);
+
+        assert_ok!(CommunityVotingPallet::delegate(RuntimeOrigin::signed(2), 1, None));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(1), 1, 0));
+        assert_ok!(CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 1));
+        assert_ok!(CommunityVotingPallet::remove_vote(RuntimeOrigin::signed(2), 1));
+
+        run_to_block(12);
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_votes, tally.yes_delegated, tally.no_votes), (1, 1, 0));
+    });
+}
+
+// Start a secret ballot on proposal 1 committing until block 6 and revealing until block 11
+fn start_secret_ballot() {
+    assert_ok!(CommunityVotingPallet::start_voting(
+        RuntimeOrigin::root(),
+        1,
+        5,
+        VoteWeighting::PerHead,
+        ThresholdPolicy::default(),
+        Secrecy::Secret { reveal_blocks: 5 }
+    ));
+}
+
+// Commit `vote` on proposal 1 from `voter`, salted with the voter's ID
+fn commit(voter: u64, vote: u8) {
+    let commitment = CommunityVotingPallet::commitment(1, &voter, vote, &[voter as u8; 32]);
+    assert_ok!(CommunityVotingPallet::commit_vote(RuntimeOrigin::signed(voter), 1, commitment));
+}
+
+// Test only valid reveals of secret votes are counted and unrevealed commits are reported
+#[test]
+fn secret_ballot_counts_valid_reveals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_secret_ballot();
+        assert_eq!(VotingPeriods::<Test>::get(1).unwrap().reveal_end_block, Some(11));
+
+        // Commit phase
+        assert_noop!(
+            CommunityVotingPallet::cast_vote(RuntimeOrigin::signed(2), 1, 0),
+            Error::<Test>::SecretBallot
+        );
+        commit(2, 0);
+        System::assert_last_event(Event::VoteCommitted { proposal_id: 1, voter: 2 }.into());
+        commit(3, 1);
+        commit(4, 0);
+        assert_eq!(VoteCounts::<Test>::get(1).unrevealed, 3);
+        assert_noop!(
+            CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(2), 1, 0, [2; 32]),
+            Error::<Test>::NotRevealPhase
+        );
+
+        // Reveal phase
+        System::set_block_number(7);
+        assert_noop!(
+            CommunityVotingPallet::commit_vote(RuntimeOrigin::signed(5), 1, Default::default()),
+            Error::<Test>::VotingPeriodEnded
+        );
+        assert_ok!(CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(2), 1, 0, [2; 32]));
+        System::assert_last_event(Event::VoteRevealed { proposal_id: 1, voter: 2, vote: 0 }.into());
+        assert_ok!(CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(3), 1, 1, [3; 32]));
+        assert_noop!(
+            CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(4), 1, 1, [4; 32]),
+            Error::<Test>::InvalidReveal
+        );
+        assert_noop!(
+            CommunityVotingPallet::end_voting(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::VotingPeriodNotStarted
+        );
+        let tally = VoteCounts::<Test>::get(1);
+        assert_eq!((tally.yes_votes, tally.no_votes), (1, 1));
+
+        // Closed after the reveal phase
+        run_to_block(12);
+        System::assert_has_event(Event::VotesUnrevealed { proposal_id: 1, count: 1 }.into());
+        assert_eq!(VoteCounts::<Test>::get(1).unrevealed, 1);
+        assert_eq!(VoteCommitments::<Test>::iter_prefix(1).count(), 0);
+        assert_eq!(VotingResults::<Test>::get(1), Some(VoteResult::Failed));
+    });
+}
+
+// Test a commitment copied from another voter cannot be revealed
+#[test]
+fn copied_commitment_cannot_be_revealed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_secret_ballot();
+
+        // Voter 3 copies voter 2's published commitment
+        commit(2, 0);
+        let copied = VoteCommitments::<Test>::get(1, 2).unwrap();
+        assert_ok!(CommunityVotingPallet::commit_vote(RuntimeOrigin::signed(3), 1, copied));
+
+        // The vote and salt are public once voter 2 reveals
+        System::set_block_number(7);
+        assert_ok!(CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(2), 1, 0, [2; 32]));
+        assert_noop!(
+            CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(3), 1, 0, [2; 32]),
+            Error::<Test>::InvalidReveal
+        );
+        assert_eq!(VoteCounts::<Test>::get(1).yes_votes, 1);
+    });
+}
+
+// Test unrevealed commitments are counted once per voter and cleared across blocks
+#[test]
+fn unrevealed_commitments_are_cleared_across_blocks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_secret_ballot();
+        MaxCountSteps::set(1);
+
+        // A replaced commitment is counted once
+        commit(2, 1);
+        commit(2, 0);
+        assert_eq!(VoteCounts::<Test>::get(1).unrevealed, 1);
+        for voter in 3..=6 {
+            commit(voter, 0);
+        }
+        assert_eq!(VoteCounts::<Test>::get(1).unrevealed, 5);
+
+        System::set_block_number(7);
+        assert_ok!(CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(2), 1, 0, [2; 32]));
+        assert_eq!(VoteCounts::<Test>::get(1).unrevealed, 4);
+
+        // The result is stored before the commitments are cleared
+        while VotingResults::<Test>::get(1).is_none() {
+            run_to_block(System::block_number() + 1);
+        }
+        System::assert_has_event(Event::VotesUnrevealed { proposal_id: 1, count: 4 }.into());
+        assert_eq!(VoteCommitments::<Test>::iter_prefix(1).count(), 4);
+
+        // Up to `MaxDelegators` commitments are cleared per step
+        run_to_block(System::block_number() + 1);
+        assert_eq!(VoteCommitments::<Test>::iter_prefix(1).count(), 1);
+        assert!(DelegationCounts::<Test>::contains_key(1));
+        run_to_block(System::block_number() + 1);
+        assert!(!VoteCommitments::<Test>::contains_prefix(1));
+        assert!(!DelegationCounts::<Test>::contains_key(1));
+    });
+}
+
+// Test secret ballot configuration and reveal errors
+#[test]
+fn secret_ballot_fails_for_invalid_use() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for (weighting, reveal_blocks) in [(VoteWeighting::PerHead, 0), (VoteWeighting::Stake, 5)] {
+            assert_noop!(
+                CommunityVotingPallet::start_voting(
+                    RuntimeOrigin::root(),
+                    1,
+                    5,
+                    weighting,
+                    ThresholdPolicy::default(),
+                    Secrecy::Secret { reveal_blocks }
+                ),
+                Error::<Test>::InvalidSecrecy
+            );
+        }
+
+        start_per_head(2);
+        assert_noop!(
+            CommunityVotingPallet::commit_vote(RuntimeOrigin::signed(2), 2, Default::default()),
+            Error::<Test>::NotSecretBallot
+        );
+
+        start_secret_ballot();
+        assert_noop!(
+            CommunityVotingPallet::commit_vote(RuntimeOrigin::signed(11), 1, Default::default()),
+            Error::<Test>::NotRegistered
+        );
+        System::set_block_number(7);
+        assert_noop!(
+            CommunityVotingPallet::reveal_vote(RuntimeOrigin::signed(2), 1, 0, [2; 32]),
+            Error::<Test>::NoCommitment
+        );
+    });
+}
+
+// Test the counting methods on the same selections
+#[test]
+fn counting_methods() {
+    let selections = vec![vec![0, 1], vec![0, 1], vec![1, 0], vec![2, 1], vec![2, 1]];
+
+    assert_eq!(
+        CountingMethod::Plurality.count(3, &selections),
+        (BallotResult::Tie, vec![vec![2, 1, 2]])
+    );
+    assert_eq!(
+        CountingMethod::Approval.count(3, &selections),
+        (BallotResult::Winner(1), vec![vec![3, 5, 2]])
+    );
+    // The second preference on the ballot for 1 decides the runoff
+    assert_eq!(
+        CountingMethod::InstantRunoff.count(3, &selections),
+        (BallotResult::Winner(0), vec![vec![2, 1, 2], vec![3, 0, 2]])
+    );
+
+    assert_eq!(
+        CountingMethod::InstantRunoff.count(2, &[vec![0], vec![1]]),
+        (BallotResult::Tie, vec![vec![1, 1]])
+    );
+    assert_eq!(
+        CountingMethod::Plurality.count(2, &[]),
+        (BallotResult::NoVotes, vec![vec![0, 0]])
+    );
+}
+
+// Test an instant-runoff ballot is counted with every round when it closes
+#[test]
+fn ranked_choice_ballot_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let designs = vec![b"Arch".to_vec(), b"Suspension".to_vec(), b"Cable-stayed".to_vec()];
+        assert_ok!(CommunityVotingPallet::create_ballot(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            designs,
+            CountingMethod::InstantRunoff
+        ));
+        System::assert_last_event(
+            Event::BallotCreated {
+                proposal_id: 1,
+                options: 3,
+                method: CountingMethod::InstantRunoff,
+            }
+            .into(),
+        );
+
+        let rankings = [vec![0, 1], vec![0, 1], vec![1, 0], vec![2, 1], vec![2, 1]];
+        for (voter, ranking) in (1..).zip(rankings) {
+            assert_ok!(CommunityVotingPallet::cast_ballot(
+                RuntimeOrigin::signed(voter),
+                1,
+                ranking
+            ));
+        }
+        System::assert_last_event(Event::BallotCast { proposal_id: 1, voter: 5 }.into());
+        assert_eq!(BallotSelections::<Test>::get(1, 3).unwrap().into_inner(), vec![1, 0]);
+
+        run_to_block(7);
+        System::assert_last_event(
+            Event::BallotEnded { proposal_id: 1, result: BallotResult::Winner(0) }.into(),
+        );
+        assert_eq!(BallotResults::<Test>::get(1), Some(BallotResult::Winner(0)));
+        let rounds = BallotRounds::<Test>::get(1).into_iter().map(|round| round.into_inner());
+        assert_eq!(rounds.collect::<Vec<_>>(), vec![vec![2, 1, 2], vec![3, 0, 2]]);
+        assert_noop!(
+            CommunityVotingPallet::end_ballot(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::VotingAlreadyEnded
+        );
+    });
+}
+
+// Test an instant-runoff ballot is counted a round at a time across blocks
+#[test]
+fn ranked_choice_ballot_is_counted_across_blocks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxSelectionsPerBlock::set(2);
+
+        let designs = vec![b"Arch".to_vec(), b"Suspension".to_vec(), b"Cable-stayed".to_vec()];
+        assert_ok!(CommunityVotingPallet::create_ballot(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            designs,
+            CountingMethod::InstantRunoff
+        ));
+        let rankings = [vec![0, 1], vec![0, 1], vec![1, 0], vec![2, 1], vec![2, 1]];
+        for (voter, ranking) in (1..).zip(rankings) {
+            assert_ok!(CommunityVotingPallet::cast_ballot(
+                RuntimeOrigin::signed(voter),
+                1,
+                ranking
+            ));
+        }
+
+        // Each round counts every selection, then concludes
+        run_to_block(9);
+        let rounds = BallotRounds::<Test>::get(1).into_iter().map(|round| round.into_inner());
+        assert_eq!(rounds.collect::<Vec<_>>(), vec![vec![2, 1, 2]]);
+        assert_eq!(BallotResults::<Test>::get(1), None);
+        run_to_block(11);
+        assert_eq!(BallotResults::<Test>::get(1), None);
+        let votes = BallotCounts::<Test>::get(1).unwrap().votes;
+        assert_eq!(votes.iter().sum::<u32>(), 4);
+        assert_eq!(votes[1], 0);
+
+        // Ending the ballot continues the count
+        assert_ok!(CommunityVotingPallet::end_ballot(RuntimeOrigin::signed(1), 1));
+        System::assert_last_event(
+            Event::BallotEnded { proposal_id: 1, result: BallotResult::Winner(0) }.into(),
+        );
+        let rounds = BallotRounds::<Test>::get(1).into_iter().map(|round| round.into_inner());
+        assert_eq!(rounds.collect::<Vec<_>>(), vec![vec![2, 1, 2], vec![3, 0, 2]]);
+        assert!(!BallotCounts::<Test>::contains_key(1));
+
+        System::reset_events();
+        run_to_block(12);
+        assert!(System::events().is_empty());
+    });
+}
+
+// Test ballots can be ended manually once they are over
+#[test]
+fn end_ballot_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::create_ballot(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            vec![b"A".to_vec(), b"B".to_vec()],
+            CountingMethod::Plurality
+        ));
+        assert_ok!(CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(2), 1, vec![1]));
+
+        assert_noop!(
+            CommunityVotingPallet::end_ballot(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::VotingPeriodNotStarted
+        );
+        System::set_block_number(7);
+        assert_ok!(CommunityVotingPallet::end_ballot(RuntimeOrigin::signed(1), 1));
+        assert_eq!(BallotResults::<Test>::get(1), Some(BallotResult::Winner(1)));
+
+        // The scheduled closure does not count it again
+        System::reset_events();
+        CommunityVotingPallet::on_initialize(7);
+        assert!(System::events().is_empty());
+    });
+}
+
+// Test ballot definitions are validated
+#[test]
+fn create_ballot_fails_for_invalid_ballot() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let create = |proposal_id, options: Vec<Vec<u8>>| {
+            CommunityVotingPallet::create_ballot(
+                RuntimeOrigin::root(),
+                proposal_id,
+                5,
+                options,
+                CountingMethod::Approval,
+            )
+        };
+
+        assert_noop!(create(1, vec![b"A".to_vec()]), Error::<Test>::InvalidOptions);
+        assert_noop!(create(1, vec![b"A".to_vec(); 5]), Error::<Test>::InvalidOptions);
+        assert_noop!(create(1, vec![b"A".to_vec(), vec![0; 65]]), Error::<Test>::InvalidOptions);
+        assert_noop!(
+            create(UNKNOWN_PROPOSAL, vec![b"A".to_vec(), b"B".to_vec()]),
+            Error::<Test>::ProposalDoesNotExist
+        );
+
+        // A proposal is put to a vote only once
+        start_per_head(2);
+        assert_noop!(
+            create(2, vec![b"A".to_vec(), b"B".to_vec()]),
+            Error::<Test>::VotingAlreadyStarted
+        );
+        assert_ok!(create(1, vec![b"A".to_vec(), b"B".to_vec()]));
+        assert_noop!(
+            CommunityVotingPallet::start_voting(
+                RuntimeOrigin::root(),
+                1,
+                5,
+                VoteWeighting::PerHead,
+                ThresholdPolicy::default(),
+                Secrecy::Public
+            ),
+            Error::<Test>::VotingAlreadyStarted
+        );
+    });
+}
+
+// Test selections must fit the ballot
+#[test]
+fn cast_ballot_fails_for_invalid_selection() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CommunityVotingPallet::create_ballot(
+            RuntimeOrigin::root(),
+            1,
+            5,
+            vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()],
+            CountingMethod::Plurality
+        ));
+
+        for selection in [vec![], vec![0, 1], vec![3]] {
+            assert_noop!(
+                CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(2), 1, selection),
+                Error::<Test>::InvalidSelection
+            );
+        }
+        assert_noop!(
+            CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(11), 1, vec![0]),
+            Error::<Test>::NotRegistered
+        );
+        assert_ok!(CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(2), 1, vec![0]));
+        assert_noop!(
+            CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(2), 1, vec![1]),
+            Error::<Test>::AlreadyVoted
+        );
+
+        // Options cannot be ranked twice
+        assert_ok!(CommunityVotingPallet::create_ballot(
+            RuntimeOrigin::root(),
+            2,
+            5,
+            vec![b"A".to_vec(), b"B".to_vec()],
+            CountingMethod::InstantRunoff
+        ));
+        assert_noop!(
+            CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(2), 2, vec![1, 1]),
+            Error::<Test>::InvalidSelection
+        );
+
+        System::set_block_number(7);
+        assert_noop!(
+            CommunityVotingPallet::cast_ballot(RuntimeOrigin::signed(3), 1, vec![0]),
+            Error::<Test>::VotingPeriodEnded
+        );
+    });
+}
@@ -6,6 +6,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `emmanuel-ThinkPad-T470-W10DG`, CPU: `Intel(R) Core(TM) i5-6300U CPU @ 2.40GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//!
+//! PLACEHOLDER WEIGHTS: only `start_voting`, `cast_vote`, `end_voting` and `cast_vote_no`
+//! were measured by the run above, and their calls have changed since. The other weights are
+//! estimated by hand from the storage each call accesses. Regenerate this file with the command
+//! below over the worst-case components before using these weights in production.
 
 // Executed Command:
 // frame-omni-bencher
@@ -36,121 +41,806 @@ pub trait WeightInfo {
 	fn cast_vote() -> Weight;
 	fn end_voting() -> Weight;
 	fn cast_vote_no() -> Weight;
+	fn close_voting_periods(n: u32) -> Weight;
+	fn cast_staked_vote() -> Weight;
+	fn unlock() -> Weight;
+	fn register_citizen() -> Weight;
+	fn revoke_citizen() -> Weight;
+	fn remove_expired_registration() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn change_vote() -> Weight;
+	fn remove_vote() -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn create_ballot(n: u32) -> Weight;
+	fn cast_ballot(n: u32) -> Weight;
+	fn end_ballot() -> Weight;
+	fn count_delegators() -> Weight;
+	fn count_selections(n: u32) -> Weight;
 }
 
 /// Weights for `community_voting` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `BudgetProposal::BudgetProposals` (r:1 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::VoteCounts` (r:0 w:1)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
-	/// Storage: `CommunityVoting::VotingPeriods` (r:0 w:1)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:1)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingCloses` (r:0 w:1)
+	/// Proof: `CommunityVoting::VotingCloses` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn start_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `3497`
 		// Minimum execution time: 44_544_000 picoseconds.
-		Weight::from_parts(90_044_000, 0)
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(90_044_000, 3497)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
 	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn cast_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `148`
-		//  Estimated: `3522`
+		//  Estimated: `3529`
 		// Minimum execution time: 139_214_000 picoseconds.
-		Weight::from_parts(289_579_000, 3522)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		Weight::from_parts(289_579_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:0)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingResults` (r:1 w:1)
+	/// Proof: `CommunityVoting::VotingResults` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:0)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:1 w:0)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
 	fn end_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `148`
-		//  Estimated: `3497`
+		//  Estimated: `7207`
 		// Minimum execution time: 185_202_000 picoseconds.
-		Weight::from_parts(195_086_000, 3497)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(195_086_000, 7207)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
 	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn cast_vote_no() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `148`
-		//  Estimated: `3522`
+		//  Estimated: `3529`
 		// Minimum execution time: 251_736_000 picoseconds.
-		Weight::from_parts(342_835_000, 3522)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		Weight::from_parts(342_835_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::CloseBacklog` (r:1 w:1)
+	/// Proof: `CommunityVoting::CloseBacklog` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingCloses` (r:51 w:50)
+	/// Proof: `CommunityVoting::VotingCloses` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingResults` (r:50 w:50)
+	/// Proof: `CommunityVoting::VotingResults` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:50 w:0)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `BudgetProposal::BudgetProposals` (r:50 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn close_voting_periods(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `3882`
+		// Minimum execution time: 2_605_000 picoseconds.
+		Weight::from_parts(5_210_000, 3882)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(31_840_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2874).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn cast_staked_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `561`
+		//  Estimated: `4146`
+		// Minimum execution time: 156_240_000 picoseconds.
+		Weight::from_parts(312_480_000, 4146)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1179`
+		//  Estimated: `4764`
+		// Minimum execution time: 93_120_000 picoseconds.
+		Weight::from_parts(186_240_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:1)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CitizenCount` (r:1 w:1)
+	/// Proof: `CommunityVoting::CitizenCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn register_citizen() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3529`
+		// Minimum execution time: 12_155_000 picoseconds.
+		Weight::from_parts(24_310_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:1)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CitizenCount` (r:1 w:1)
+	/// Proof: `CommunityVoting::CitizenCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn revoke_citizen() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3529`
+		// Minimum execution time: 11_435_000 picoseconds.
+		Weight::from_parts(22_870_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:1)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CitizenCount` (r:1 w:1)
+	/// Proof: `CommunityVoting::CitizenCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_expired_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3529`
+		// Minimum execution time: 11_727_500 picoseconds.
+		Weight::from_parts(23_455_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegations` (r:1 w:1)
+	/// Proof: `CommunityVoting::Delegations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:2 w:2)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10839`
+		//  Estimated: `14424`
+		// Minimum execution time: 30_865_000 picoseconds.
+		Weight::from_parts(61_730_000, 14424)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CommunityVoting::Delegations` (r:1 w:1)
+	/// Proof: `CommunityVoting::Delegations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:1 w:1)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3622`
+		//  Estimated: `7207`
+		// Minimum execution time: 19_470_000 picoseconds.
+		Weight::from_parts(38_940_000, 7207)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:0)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `881`
+		//  Estimated: `4466`
+		// Minimum execution time: 71_330_000 picoseconds.
+		Weight::from_parts(142_660_000, 4466)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1179`
+		//  Estimated: `4764`
+		// Minimum execution time: 118_205_000 picoseconds.
+		Weight::from_parts(236_410_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCommitments` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCommitments` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3557`
+		// Minimum execution time: 49_160_000 picoseconds.
+		Weight::from_parts(98_320_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCommitments` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCommitments` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:0 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3557`
+		// Minimum execution time: 82_435_000 picoseconds.
+		Weight::from_parts(164_870_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `BudgetProposal::BudgetProposals` (r:1 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:1)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingCloses` (r:0 w:1)
+	/// Proof: `CommunityVoting::VotingCloses` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotOptions` (r:0 w:1)
+	/// Proof: `CommunityVoting::BallotOptions` (`max_values`: None, `max_size`: Some(677), added: 3152, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 10]`.
+	fn create_ballot(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `4142`
+		// Minimum execution time: 32_105_000 picoseconds.
+		Weight::from_parts(64_210_000, 4142)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(1_870_000, 0).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:0)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotSelections` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotSelections` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotOptions` (r:1 w:0)
+	/// Proof: `CommunityVoting::BallotOptions` (`max_values`: None, `max_size`: Some(677), added: 3152, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn cast_ballot(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `4142`
+		// Minimum execution time: 44_270_000 picoseconds.
+		Weight::from_parts(88_540_000, 4142)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(940_000, 0).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:0)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotResults` (r:1 w:0)
+	/// Proof: `CommunityVoting::BallotResults` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotCounts` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotOptions` (r:1 w:0)
+	/// Proof: `CommunityVoting::BallotOptions` (`max_values`: None, `max_size`: Some(677), added: 3152, mode: `MaxEncodedLen`)
+	fn end_ballot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `4142`
+		// Minimum execution time: 41_310_000 picoseconds.
+		Weight::from_parts(82_620_000, 4142)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CommunityVoting::DelegationCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::DelegationCounts` (`max_values`: None, `max_size`: Some(18039), added: 20514, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `BudgetProposal::BudgetProposals` (r:1 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:101 w:0)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:1 w:0)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegations` (r:200 w:0)
+	/// Proof: `CommunityVoting::Delegations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CountedDelegators` (r:100 w:100)
+	/// Proof: `CommunityVoting::CountedDelegators` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:100 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn count_delegators() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28613`
+		//  Estimated: `20514`
+		// Minimum execution time: 1_104_310_000 picoseconds.
+		Weight::from_parts(1_208_620_000, 20514)
+			.saturating_add(T::DbWeight::get().reads(506_u64))
+			.saturating_add(T::DbWeight::get().writes(102_u64))
+	}
+	/// Storage: `CommunityVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotCounts` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:0)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotSelections` (r:500 w:0)
+	/// Proof: `CommunityVoting::BallotSelections` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotRounds` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotRounds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotResults` (r:0 w:1)
+	/// Proof: `CommunityVoting::BallotResults` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 500]`.
+	fn count_selections(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (97 ±0)`
+		//  Estimated: `3892 + n * (2572 ±0)`
+		// Minimum execution time: 28_740_000 picoseconds.
+		Weight::from_parts(57_480_000, 3892)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(6_210_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2572).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
+	/// Storage: `BudgetProposal::BudgetProposals` (r:1 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::VoteCounts` (r:0 w:1)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
-	/// Storage: `CommunityVoting::VotingPeriods` (r:0 w:1)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:1)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingCloses` (r:0 w:1)
+	/// Proof: `CommunityVoting::VotingCloses` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn start_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `3497`
 		// Minimum execution time: 44_544_000 picoseconds.
-		Weight::from_parts(90_044_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(90_044_000, 3497)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
 	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn cast_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `148`
-		//  Estimated: `3522`
+		//  Estimated: `3529`
 		// Minimum execution time: 139_214_000 picoseconds.
-		Weight::from_parts(289_579_000, 3522)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		Weight::from_parts(289_579_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:0)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingResults` (r:1 w:1)
+	/// Proof: `CommunityVoting::VotingResults` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:0)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:1 w:0)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
 	fn end_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `148`
-		//  Estimated: `3497`
+		//  Estimated: `7207`
 		// Minimum execution time: 185_202_000 picoseconds.
-		Weight::from_parts(195_086_000, 3497)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		Weight::from_parts(195_086_000, 7207)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
-	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
 	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
-	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn cast_vote_no() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `148`
-		//  Estimated: `3522`
+		//  Estimated: `3529`
 		// Minimum execution time: 251_736_000 picoseconds.
-		Weight::from_parts(342_835_000, 3522)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		Weight::from_parts(342_835_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::CloseBacklog` (r:1 w:1)
+	/// Proof: `CommunityVoting::CloseBacklog` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingCloses` (r:51 w:50)
+	/// Proof: `CommunityVoting::VotingCloses` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingResults` (r:50 w:50)
+	/// Proof: `CommunityVoting::VotingResults` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:50 w:0)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `BudgetProposal::BudgetProposals` (r:50 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn close_voting_periods(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `3882`
+		// Minimum execution time: 2_605_000 picoseconds.
+		Weight::from_parts(5_210_000, 3882)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(31_840_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2874).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn cast_staked_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `561`
+		//  Estimated: `4146`
+		// Minimum execution time: 156_240_000 picoseconds.
+		Weight::from_parts(312_480_000, 4146)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1179`
+		//  Estimated: `4764`
+		// Minimum execution time: 93_120_000 picoseconds.
+		Weight::from_parts(186_240_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:1)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CitizenCount` (r:1 w:1)
+	/// Proof: `CommunityVoting::CitizenCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn register_citizen() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3529`
+		// Minimum execution time: 12_155_000 picoseconds.
+		Weight::from_parts(24_310_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:1)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CitizenCount` (r:1 w:1)
+	/// Proof: `CommunityVoting::CitizenCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn revoke_citizen() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3529`
+		// Minimum execution time: 11_435_000 picoseconds.
+		Weight::from_parts(22_870_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:1)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CitizenCount` (r:1 w:1)
+	/// Proof: `CommunityVoting::CitizenCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_expired_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3529`
+		// Minimum execution time: 11_727_500 picoseconds.
+		Weight::from_parts(23_455_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegations` (r:1 w:1)
+	/// Proof: `CommunityVoting::Delegations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:2 w:2)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10839`
+		//  Estimated: `14424`
+		// Minimum execution time: 30_865_000 picoseconds.
+		Weight::from_parts(61_730_000, 14424)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CommunityVoting::Delegations` (r:1 w:1)
+	/// Proof: `CommunityVoting::Delegations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:1 w:1)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3622`
+		//  Estimated: `7207`
+		// Minimum execution time: 19_470_000 picoseconds.
+		Weight::from_parts(38_940_000, 7207)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:0)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `881`
+		//  Estimated: `4466`
+		// Minimum execution time: 71_330_000 picoseconds.
+		Weight::from_parts(142_660_000, 4466)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:1 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoterStakes` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoterStakes` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1179`
+		//  Estimated: `4764`
+		// Minimum execution time: 118_205_000 picoseconds.
+		Weight::from_parts(236_410_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCommitments` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCommitments` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3557`
+		// Minimum execution time: 49_160_000 picoseconds.
+		Weight::from_parts(98_320_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCommitments` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCommitments` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:0 w:1)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3557`
+		// Minimum execution time: 82_435_000 picoseconds.
+		Weight::from_parts(164_870_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `BudgetProposal::BudgetProposals` (r:1 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:1)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingCloses` (r:0 w:1)
+	/// Proof: `CommunityVoting::VotingCloses` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotOptions` (r:0 w:1)
+	/// Proof: `CommunityVoting::BallotOptions` (`max_values`: None, `max_size`: Some(677), added: 3152, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 10]`.
+	fn create_ballot(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `4142`
+		// Minimum execution time: 32_105_000 picoseconds.
+		Weight::from_parts(64_210_000, 4142)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(1_870_000, 0).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityVoting::Citizens` (r:1 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:0)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotSelections` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotSelections` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotOptions` (r:1 w:0)
+	/// Proof: `CommunityVoting::BallotOptions` (`max_values`: None, `max_size`: Some(677), added: 3152, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn cast_ballot(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `4142`
+		// Minimum execution time: 44_270_000 picoseconds.
+		Weight::from_parts(88_540_000, 4142)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(940_000, 0).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:0)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotResults` (r:1 w:0)
+	/// Proof: `CommunityVoting::BallotResults` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotCounts` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotOptions` (r:1 w:0)
+	/// Proof: `CommunityVoting::BallotOptions` (`max_values`: None, `max_size`: Some(677), added: 3152, mode: `MaxEncodedLen`)
+	fn end_ballot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `4142`
+		// Minimum execution time: 41_310_000 picoseconds.
+		Weight::from_parts(82_620_000, 4142)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CommunityVoting::DelegationCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::DelegationCounts` (`max_values`: None, `max_size`: Some(18039), added: 20514, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VotingPeriods` (r:1 w:0)
+	/// Proof: `CommunityVoting::VotingPeriods` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `BudgetProposal::BudgetProposals` (r:1 w:0)
+	/// Proof: `BudgetProposal::BudgetProposals` (`max_values`: None, `max_size`: Some(399), added: 2874, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::VoteCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::VoteCounts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::ProposalVotes` (r:101 w:0)
+	/// Proof: `CommunityVoting::ProposalVotes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegators` (r:1 w:0)
+	/// Proof: `CommunityVoting::Delegators` (`max_values`: None, `max_size`: Some(3742), added: 6217, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Delegations` (r:200 w:0)
+	/// Proof: `CommunityVoting::Delegations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::CountedDelegators` (r:100 w:100)
+	/// Proof: `CommunityVoting::CountedDelegators` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Citizens` (r:100 w:0)
+	/// Proof: `CommunityVoting::Citizens` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn count_delegators() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28613`
+		//  Estimated: `20514`
+		// Minimum execution time: 1_104_310_000 picoseconds.
+		Weight::from_parts(1_208_620_000, 20514)
+			.saturating_add(RocksDbWeight::get().reads(506_u64))
+			.saturating_add(RocksDbWeight::get().writes(102_u64))
+	}
+	/// Storage: `CommunityVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotCounts` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::Ballots` (r:1 w:0)
+	/// Proof: `CommunityVoting::Ballots` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotSelections` (r:500 w:0)
+	/// Proof: `CommunityVoting::BallotSelections` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotRounds` (r:1 w:1)
+	/// Proof: `CommunityVoting::BallotRounds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityVoting::BallotResults` (r:0 w:1)
+	/// Proof: `CommunityVoting::BallotResults` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 500]`.
+	fn count_selections(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (97 ±0)`
+		//  Estimated: `3892 + n * (2572 ±0)`
+		// Minimum execution time: 28_740_000 picoseconds.
+		Weight::from_parts(57_480_000, 3892)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(6_210_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2572).saturating_mul(n.into()))
+	}
 }
@@ -71,6 +71,8 @@ pub mod pallet {
         WalletDoesNotExist,
         /// Invalid department name.
         InvalidDepartment,
+        /// Wallet balance is too low.
+        InsufficientBalance,
     }
 
     // Wallet details structure
@@ -183,6 +185,22 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// Debit `amount` spent by a government wallet from its balance.
+        pub fn debit(wallet_id: &T::AccountId, amount: u128) -> DispatchResult {
+            // Check if wallet exists
+            if !GovernmentWallets::<T>::contains_key(wallet_id) {
+                return Err(Error::<T>::WalletDoesNotExist.into());
+            }
+
+            // Update wallet balance
+            WalletBalances::<T>::try_mutate(wallet_id, |balance| {
+                *balance = balance.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+                Ok(())
+            })
+        }
+    }
 }
 #[cfg(test)]
 mod mock;
@@ -1,4 +1,4 @@
-use crate::{mock::*, Error, Event};
+use crate::{mock::*, Error, Event, WalletBalances};
 use frame::deps::sp_runtime;
 use frame::testing_prelude::*;
 
@@ -511,4 +511,38 @@ fn allocation_with_long_purpose_works() {
         
         // Should work since purpose length is not validated
     });
-}
\ No newline at end of file
+}
+// Test spending is debited from the wallet balance
+#[test]
+fn debit_reduces_wallet_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(GovernmentWalletPallet::create_wallet(
+            RuntimeOrigin::signed(1),
+            10,
+            b"Health Department".to_vec()
+        ));
+        assert_ok!(GovernmentWalletPallet::allocate_funds(
+            RuntimeOrigin::signed(2),
+            10,
+            5000,
+            b"Medical supplies".to_vec()
+        ));
+
+        assert_ok!(GovernmentWalletPallet::debit(&10, 2000));
+        assert_eq!(WalletBalances::<Test>::get(10), 3000);
+
+        // Cannot spend more than the wallet holds
+        assert_noop!(
+            GovernmentWalletPallet::debit(&10, 3001),
+            Error::<Test>::InsufficientBalance
+        );
+
+        // Unknown wallets cannot be debited
+        assert_noop!(
+            GovernmentWalletPallet::debit(&11, 1),
+            Error::<Test>::WalletDoesNotExist
+        );
+    });
+}
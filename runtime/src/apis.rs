@@ -45,9 +45,10 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-	SLOT_DURATION, VERSION,
+	configs::AssetKind,
+	AccountId, Balance, Block, BudgetProposal, ConsensusHook, Executive, InherentDataExt, Nonce,
+	ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+	TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 // we move some impls outside so we can easily use them with `docify`.
@@ -219,6 +220,48 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl budget_proposal::runtime_api::BudgetProposalApi<Block, AccountId, AssetKind, Balance>
+		for Runtime
+	{
+		fn current_fiscal_period() -> u32 {
+			BudgetProposal::current_fiscal_period()
+		}
+
+		fn remaining_budget(period: u32, asset: AssetKind) -> Option<Balance> {
+			BudgetProposal::remaining_budget(period, asset)
+		}
+
+		fn category_budget(
+			period: u32,
+			category: u32,
+			asset: AssetKind,
+		) -> budget_proposal::FiscalBudget<Balance> {
+			budget_proposal::CategoryBudgets::<Runtime>::get((period, category, asset))
+		}
+
+		fn conflict_declarations(
+			approver: AccountId,
+		) -> Vec<(AccountId, budget_proposal::ConflictDeclaration)> {
+			BudgetProposal::conflict_declarations(&approver)
+		}
+
+		fn recusals(proposal_id: u64) -> Vec<AccountId> {
+			BudgetProposal::recusals(proposal_id)
+		}
+
+		fn proposals(
+			filter: budget_proposal::ProposalFilter<AccountId, Balance>,
+			start: u64,
+			limit: u32,
+		) -> Vec<(u64, budget_proposal::ProposalDetails<AccountId, Balance, AssetKind>)> {
+			BudgetProposal::proposals(filter, start, limit)
+		}
+
+		fn proposal_totals(asset: AssetKind) -> budget_proposal::ProposalTotals<Balance> {
+			BudgetProposal::proposal_totals(asset)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -38,4 +38,5 @@ polkadot_sdk::frame_benchmarking::define_benchmarks!(
     [budget_proposal, BudgetProposal]
     [community_voting, CommunityVoting]
     [government_wallet, GovernmentWallet]
+    [pallet_membership, BudgetApprovers]
 );
@@ -37,14 +37,16 @@ use frame_support::{
 	dispatch::DispatchClass,
 	parameter_types,
 	traits::{
-		ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, TransformOrigin, VariantCountOf,
+		fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
+		EnsureOrigin, TransformOrigin, VariantCountOf,
 	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureRoot,
+	EnsureRoot, EnsureSigned, EnsureSignedBy,
 };
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
@@ -52,7 +54,7 @@ use polkadot_runtime_common::{
 	xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::Perbill;
+use sp_runtime::{DispatchResult, Perbill};
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
 
@@ -60,11 +62,12 @@ use xcm::latest::prelude::BodyId;
 use super::OriginCaller;
 use super::{
 	weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, Hash,
-	MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
-	System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, HOURS,
-	MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+	AccountId, Assets, Aura, Balance, Balances, Block, BlockNumber, BudgetApprovers, BudgetProposal,
+	CollatorSelection, CommunityVoting, ConsensusHook, Hash, MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session,
+	SessionKeys, System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS,
+	EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, MILLI_UNIT,
+	NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT, VERSION,
 };
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
@@ -171,6 +174,44 @@ impl pallet_balances::Config for Runtime {
 	type DoneSlashHandler = ();
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 10 * UNIT;
+	pub const AssetAccountDeposit: Balance = 10 * MILLI_UNIT;
+	pub const MetadataDepositBase: Balance = UNIT;
+	pub const MetadataDepositPerByte: Balance = 10 * MILLI_UNIT;
+	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Holder = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// Assets the treasury holds: the native token or an asset of `pallet_assets`.
+pub type AssetKind = NativeOrWithId<u32>;
+
+/// The native token and `pallet_assets` behind a single `fungibles` interface.
+pub type NativeAndAssets = UnionOf<Balances, Assets, NativeFromLeft, AssetKind, AccountId>;
+
 parameter_types! {
 	/// Relay Chain `TransactionByteFee` / 10
 	pub const TransactionByteFee: Balance = 10 * MICRO_UNIT;
@@ -330,22 +371,222 @@ impl pallet_utility::Config for Runtime {
     type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
 }
 
+// Configure the membership pallet holding the budget proposal approvers.
+impl pallet_membership::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRoot<AccountId>;
+	type RemoveOrigin = EnsureRoot<AccountId>;
+	type SwapOrigin = EnsureRoot<AccountId>;
+	type ResetOrigin = EnsureRoot<AccountId>;
+	type PrimeOrigin = EnsureRoot<AccountId>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = ConstU32<32>;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
 // Configure audit-log pallet
 impl audit_log::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = audit_log::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ProposalLifetime: u64 = 30 * DAYS as u64;
+	pub const FiscalPeriodLength: u64 = 365 * DAYS as u64;
+	pub const ChallengePeriod: u64 = 7 * DAYS as u64;
+	pub const ReferendumThreshold: Balance = 1_000_000 * UNIT;
+	pub const ReferendumDuration: u64 = 14 * DAYS as u64;
+	/// Share of the registered electorate that must vote for a budget referendum to count.
+	pub const BudgetReferendumTurnout: Perbill = Perbill::from_percent(20);
+	/// Share of the yes and no votes a budget referendum must win.
+	pub const BudgetReferendumApproval: community_voting::Approval =
+		community_voting::Approval::SixtyPercent;
+	pub const VoteLockingPeriod: u64 = 7 * DAYS as u64;
+	pub const ReportingPeriod: u64 = 90 * DAYS as u64;
+	pub const EndorsementStep: Balance = 100_000 * UNIT;
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+}
+
+/// Government wallets registered in `government_wallet` can pay budget proposals, debited
+/// from their `government_wallet` balance.
+pub struct RegisteredGovernmentWallets;
+impl budget_proposal::GovernmentWallets<AccountId, Balance> for RegisteredGovernmentWallets {
+	fn wallet_exists(wallet: &AccountId) -> bool {
+		government_wallet::GovernmentWallets::<Runtime>::contains_key(wallet)
+	}
+
+	fn debit(wallet: &AccountId, amount: Balance) -> DispatchResult {
+		government_wallet::Pallet::<Runtime>::debit(wallet, amount)
+	}
+}
+
+/// Citizens registered in `community_voting` can vote in participatory budgeting rounds.
+pub struct RegisteredCitizens;
+impl budget_proposal::CitizenRegistry<AccountId> for RegisteredCitizens {
+	fn is_eligible(who: &AccountId) -> bool {
+		CommunityVoting::is_registered(who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register(who: &AccountId) {
+		CommunityVoting::register_citizen(RuntimeOrigin::root(), who.clone(), Default::default(), None)
+			.expect("root can register a citizen");
+	}
+}
+
+/// Lets budget-proposal benchmarks request the native token and a registered wallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NativeAssetBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl budget_proposal::BenchmarkHelper<AssetKind, AccountId> for NativeAssetBenchmarkHelper {
+	fn asset() -> AssetKind {
+		AssetKind::Native
+	}
+
+	fn wallet() -> AccountId {
+		let wallet = AccountId::from([1u8; 32]);
+		if !government_wallet::GovernmentWallets::<Runtime>::contains_key(&wallet) {
+			government_wallet::Pallet::<Runtime>::create_wallet(
+				RuntimeOrigin::signed(wallet.clone()),
+				wallet.clone(),
+				b"Benchmark".to_vec(),
+			)
+			.expect("wallet is valid");
+		}
+		wallet
+	}
+}
+
+/// Budget proposals above the referendum threshold are put to a community vote, passing with
+/// `BudgetReferendumApproval` of the votes at a turnout of `BudgetReferendumTurnout`.
+pub struct CommunityReferenda;
+impl budget_proposal::ReferendumProvider for CommunityReferenda {
+	fn open_referendum(proposal_id: u64) -> DispatchResult {
+		CommunityVoting::open_voting(
+			proposal_id,
+			ReferendumDuration::get(),
+			community_voting::VoteWeighting::PerHead,
+			community_voting::ThresholdPolicy {
+				turnout: community_voting::Turnout::Electorate(BudgetReferendumTurnout::get()),
+				approval: BudgetReferendumApproval::get(),
+				count_abstentions: true,
+			},
+			community_voting::Secrecy::Public,
+		)
+	}
+}
+
+/// Applies the outcome of community votes to the budget proposals under referendum.
+pub struct BudgetReferendumOutcome;
+impl community_voting::OnVotingEnded for BudgetReferendumOutcome {
+	fn on_voting_ended(proposal_id: u64, result: community_voting::VoteResult) {
+		// Votes on proposals that are not under referendum are not budget decisions
+		let _ = BudgetProposal::conclude_referendum(
+			proposal_id,
+			result == community_voting::VoteResult::Passed,
+		);
+	}
+
+	fn on_voting_ended_weight() -> Weight {
+		use budget_proposal::weights::WeightInfo;
+		<Runtime as budget_proposal::Config>::WeightInfo::conclude_referendum()
+	}
+}
+
+/// Community votes can only be started on existing budget proposals.
+pub struct ExistingBudgetProposals;
+impl community_voting::ProposalProvider for ExistingBudgetProposals {
+	fn proposal_exists(proposal_id: u64) -> bool {
+		budget_proposal::BudgetProposals::<Runtime>::contains_key(proposal_id)
+	}
+
+	fn proposal_category(proposal_id: u64) -> Option<u32> {
+		budget_proposal::BudgetProposals::<Runtime>::get(proposal_id).map(|proposal| proposal.category)
+	}
+}
+
+/// Lets community-voting benchmarks vote on a freshly created budget proposal.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BudgetProposalBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl community_voting::BenchmarkHelper for BudgetProposalBenchmarkHelper {
+	fn proposal() -> u64 {
+		let proposer = AccountId::from([0u8; 32]);
+		let category = budget_proposal::NextCategoryId::<Runtime>::get();
+		let proposal_id = budget_proposal::NextProposalId::<Runtime>::get();
+		BudgetProposal::register_category(RuntimeOrigin::root(), b"Benchmark".to_vec())
+			.expect("root can register a category");
+		BudgetProposal::create_proposal(
+			RuntimeOrigin::signed(proposer.clone()),
+			1,
+			b"Benchmark".to_vec(),
+			category,
+			AssetKind::Native,
+			proposer,
+			None,
+			budget_proposal::PaymentSchedule::LumpSum,
+		)
+		.expect("proposal is valid");
+		proposal_id
+	}
+}
+
 // Configure budget-proposal pallet
 impl budget_proposal::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = budget_proposal::weights::SubstrateWeight<Runtime>;
-
+	type Balance = Balance;
+	type AssetId = AssetKind;
+	type Assets = NativeAndAssets;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = NativeAssetBenchmarkHelper;
+	type MaxRevisions = ConstU32<20>;
+	type ProposalLifetime = ProposalLifetime;
+	type MaxExpiriesPerBlock = ConstU32<50>;
+	type BudgetOrigin = EnsureRoot<AccountId>;
+	type FiscalPeriodLength = FiscalPeriodLength;
+	type ChallengePeriod = ChallengePeriod;
+	type OversightOrigin = EnsureRoot<AccountId>;
+	type EnforcementOrigin = EnsureRoot<AccountId>;
+	type MaxClawbacks = ConstU32<10>;
+	type Citizens = RegisteredCitizens;
+	type MaxRoundProposals = ConstU32<100>;
+	type BaseEndorsements = ConstU32<3>;
+	type EndorsementStep = EndorsementStep;
+	type MaxEndorsements = ConstU32<20>;
+	type EthicsOrigin = EnsureRoot<AccountId>;
+	type MaxRecurringPaymentsPerBlock = ConstU32<50>;
+	type ReportingPeriod = ReportingPeriod;
+	type MaxExpenseItems = ConstU32<50>;
+	type MaxReportingDeadlinesPerBlock = ConstU32<50>;
+	type Referenda = CommunityReferenda;
+	type ReferendumThreshold = ReferendumThreshold;
+	type Wallets = RegisteredGovernmentWallets;
+	type ApproverOrigin = EnsureSignedBy<BudgetApprovers, AccountId>;
+	type PalletId = TreasuryPalletId;
 }
 
 impl community_voting::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = community_voting::weights::SubstrateWeight<Runtime>;
+	type OnVotingEnded = BudgetReferendumOutcome;
+	type Proposals = ExistingBudgetProposals;
+	type MaxClosuresPerBlock = ConstU32<50>;
+	type MaxCountSteps = ConstU32<8>;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type Currency = Balances;
+	type MaxStakedVotes = ConstU32<20>;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type Electorate = CommunityVoting;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type VotingOrigin = EnsureRoot<AccountId>;
+	type MaxDelegators = ConstU32<100>;
+	type MaxDelegationDepth = ConstU32<5>;
+	type MaxOptions = ConstU32<10>;
+	type MaxSelectionsPerBlock = ConstU32<500>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BudgetProposalBenchmarkHelper;
 }
 
 
@@ -1,6 +1,8 @@
 use crate::{
-	AccountId, BalancesConfig, CollatorSelectionConfig, ParachainInfoConfig, PolkadotXcmConfig,
-	RuntimeGenesisConfig, SessionConfig, SessionKeys, SudoConfig, EXISTENTIAL_DEPOSIT,
+	AccountId, AssetsConfig, BalancesConfig, BudgetApproversConfig, CollatorSelectionConfig,
+	ParachainInfoConfig, PolkadotXcmConfig, RuntimeGenesisConfig, SessionConfig, SessionKeys,
+	SudoConfig,
+	EXISTENTIAL_DEPOSIT, STABLE_ASSET_ID,
 };
 
 use alloc::{vec, vec::Vec};
@@ -41,6 +43,10 @@ fn testnet_genesis(
 				.map(|k| (k, 1u128 << 60))
 				.collect::<Vec<_>>(),
 		},
+		assets: AssetsConfig { assets: vec![(STABLE_ASSET_ID, root.clone(), true, 1)] },
+		budget_approvers: BudgetApproversConfig {
+			members: vec![root.clone()].try_into().expect("one approver fits"),
+		},
 		parachain_info: ParachainInfoConfig { parachain_id: id },
 		collator_selection: CollatorSelectionConfig {
 			invulnerables: invulnerables.iter().cloned().map(|(acc, _)| acc).collect::<Vec<_>>(),